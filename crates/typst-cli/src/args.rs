@@ -8,6 +8,7 @@ use chrono::{DateTime, Utc};
 use clap::builder::{TypedValueParser, ValueParser};
 use clap::{ArgAction, Args, ColorChoice, Parser, Subcommand, ValueEnum, ValueHint};
 use semver::Version;
+use typst::layout::Paper;
 
 /// The character typically used to separate path components
 /// in environment variables.
@@ -98,6 +99,13 @@ pub struct WatchCommand {
     #[clap(flatten)]
     pub args: CompileArgs,
 
+    /// The number of recompilations for which unused cache entries are kept.
+    ///
+    /// Higher values make recompilation faster when switching between
+    /// document states, at the expense of memory.
+    #[clap(long, default_value_t = 10, value_name = "COUNT")]
+    pub cache_max_age: usize,
+
     /// Arguments for the HTTP server.
     #[cfg(feature = "http-server")]
     #[clap(flatten)]
@@ -246,6 +254,31 @@ pub struct CompileArgs {
     #[arg(long = "pdf-standard", value_delimiter = ',')]
     pub pdf_standard: Vec<PdfStandard>,
 
+    /// Downsample raster images in PDF export whose resolution at their placed
+    /// size exceeds this PPI (pixels per inch). When unspecified, images are
+    /// embedded at their full resolution. Outside of watch mode, the number of
+    /// bytes saved is printed after export.
+    #[arg(long = "pdf-image-ppi", value_parser = parse_image_ppi)]
+    pub pdf_image_ppi: Option<f64>,
+
+    /// The quality (between 1 and 100) with which images are re-encoded as
+    /// JPEG when downsampled.
+    #[arg(
+        long = "pdf-jpeg-quality",
+        default_value_t = 85,
+        value_parser = clap::value_parser!(u8).range(1..=100),
+    )]
+    pub pdf_jpeg_quality: u8,
+
+    /// Re-encode downsampled images in lossless formats as JPEG, too.
+    #[arg(long = "pdf-lossy-images", default_value_t = false)]
+    pub pdf_lossy_images: bool,
+
+    /// Produce a tagged PDF whose structure tree exposes headings, paragraphs,
+    /// and image alt text to assistive technology.
+    #[arg(long = "pdf-tagged", default_value_t = false)]
+    pub pdf_tagged: bool,
+
     /// The PPI (pixels per inch) to use for PNG export.
     #[arg(long = "ppi", default_value_t = 144.0)]
     pub ppi: f32,
@@ -290,6 +323,18 @@ pub struct WorldArgs {
     )]
     pub inputs: Vec<(String, String)>,
 
+    /// The paper size of pages whose size the document doesn't set.
+    ///
+    /// Can be a paper name like `us-letter` or `locale` to pick US Letter or
+    /// A4 based on the system's locale. Defaults to A4.
+    #[clap(
+        long = "paper",
+        env = "TYPST_PAPER",
+        value_name = "PAPER",
+        value_parser = parse_default_paper,
+    )]
+    pub paper: Option<DefaultPaper>,
+
     /// Common font arguments.
     #[clap(flatten)]
     pub font: FontArgs,
@@ -300,6 +345,10 @@ pub struct WorldArgs {
 
     /// The document's creation date formatted as a UNIX timestamp.
     ///
+    /// This pins both the PDF creation and modification dates and the date
+    /// returned by `datetime.today()`. Typst's output does not otherwise depend
+    /// on the time or on randomness, so setting this yields reproducible PDFs.
+    ///
     /// For more information, see <https://reproducible-builds.org/specs/source-date-epoch/>.
     #[clap(
         long = "creation-timestamp",
@@ -531,6 +580,15 @@ fn parse_page_number(value: &str) -> Result<NonZeroUsize, &'static str> {
     }
 }
 
+/// Parses the PPI to which images are downsampled in PDF export.
+fn parse_image_ppi(value: &str) -> Result<f64, &'static str> {
+    match value.parse::<f64>() {
+        Ok(ppi) if ppi.is_finite() && ppi > 0.0 => Ok(ppi),
+        Ok(_) => Err("the PPI must be a positive number"),
+        Err(_) => Err("not a valid number"),
+    }
+}
+
 /// The clap value parser used by `SharedArgs.input`
 fn input_value_parser() -> impl TypedValueParser<Value = Input> {
     clap::builder::OsStringValueParser::new().try_map(|value| {
@@ -574,6 +632,23 @@ fn parse_sys_input_pair(raw: &str) -> Result<(String, String), String> {
     Ok((key, val))
 }
 
+/// The paper size for pages whose size the document doesn't set.
+#[derive(Debug, Copy, Clone)]
+pub enum DefaultPaper {
+    /// Derive the paper size from the system's locale.
+    Locale,
+    /// Use the given paper size.
+    Named(Paper),
+}
+
+/// Parses a paper name or `locale`.
+fn parse_default_paper(raw: &str) -> Result<DefaultPaper, String> {
+    if raw == "locale" {
+        return Ok(DefaultPaper::Locale);
+    }
+    raw.parse().map(DefaultPaper::Named).map_err(|err: &str| err.into())
+}
+
 /// Parses a UNIX timestamp according to <https://reproducible-builds.org/specs/source-date-epoch/>
 fn parse_source_date_epoch(raw: &str) -> Result<DateTime<Utc>, String> {
     let timestamp: i64 = raw
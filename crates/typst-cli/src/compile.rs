@@ -17,12 +17,13 @@ use typst::html::HtmlDocument;
 use typst::layout::{Frame, Page, PageRanges, PagedDocument};
 use typst::syntax::{FileId, Source, Span};
 use typst::WorldExt;
-use typst_pdf::{PdfOptions, PdfStandards, Timestamp};
+use typst_pdf::{Downsample, PdfOptions, PdfStandards, Timestamp};
 
 use crate::args::{
     CompileArgs, CompileCommand, DiagnosticFormat, Input, Output, OutputFormat,
     PdfStandard, WatchCommand,
 };
+use crate::download::as_bytes_unit;
 #[cfg(feature = "http-server")]
 use crate::server::HtmlServer;
 use crate::timings::Timer;
@@ -65,6 +66,10 @@ pub struct CompileConfig {
     /// One (or multiple comma-separated) PDF standards that Typst will enforce
     /// conformance with.
     pub pdf_standards: PdfStandards,
+    /// How to downsample raster images in PDF export, if at all.
+    pub pdf_downsample: Option<Downsample>,
+    /// Whether to produce a tagged PDF.
+    pub pdf_tagged: bool,
     /// A path to write a Makefile rule describing the current compilation.
     pub make_deps: Option<PathBuf>,
     /// The PPI (pixels per inch) to use for PNG export.
@@ -141,6 +146,12 @@ impl CompileConfig {
             PdfStandards::new(&list)?
         };
 
+        let pdf_downsample = args.pdf_image_ppi.map(|ppi| Downsample {
+            ppi,
+            jpeg_quality: args.pdf_jpeg_quality,
+            lossless: !args.pdf_lossy_images,
+        });
+
         #[cfg(feature = "http-server")]
         let server = match watch {
             Some(command)
@@ -158,6 +169,8 @@ impl CompileConfig {
             output_format,
             pages,
             pdf_standards,
+            pdf_downsample,
+            pdf_tagged: args.pdf_tagged,
             creation_timestamp: args.world.creation_timestamp,
             make_deps: args.make_deps.clone(),
             ppi: args.ppi,
@@ -290,6 +303,8 @@ fn export_pdf(document: &PagedDocument, config: &CompileConfig) -> SourceResult<
         timestamp,
         page_ranges: config.pages.clone(),
         standards: config.pdf_standards.clone(),
+        downsample: config.pdf_downsample,
+        tagged: config.pdf_tagged,
     };
     let buffer = typst_pdf::pdf(document, &options)?;
     config
@@ -297,6 +312,21 @@ fn export_pdf(document: &PagedDocument, config: &CompileConfig) -> SourceResult<
         .write(&buffer)
         .map_err(|err| eco_format!("failed to write PDF file ({err})"))
         .at(Span::detached())?;
+
+    // Report how much downsampling saved by comparing against an export at
+    // full resolution. In watch mode, this would be cleared from the terminal
+    // right away, so we don't spend the time there.
+    if options.downsample.is_some() && !config.watching {
+        let full = typst_pdf::pdf(document, &PdfOptions { downsample: None, ..options })?;
+        let saved = full.len().saturating_sub(buffer.len());
+        eprintln!(
+            "note: downsampling images saved {} ({} instead of {})",
+            as_bytes_unit(saved).trim_start(),
+            as_bytes_unit(buffer.len()).trim_start(),
+            as_bytes_unit(full.len()).trim_start(),
+        );
+    }
+
     Ok(())
 }
 
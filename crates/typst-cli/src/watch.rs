@@ -70,7 +70,7 @@ pub fn watch(timer: &mut Timer, command: &WatchCommand) -> StrResult<()> {
         timer.record(&mut world, |world| compile_once(world, &mut config))??;
 
         // Evict the cache.
-        comemo::evict(10);
+        comemo::evict(command.cache_max_age);
 
         // Adjust the file watching.
         watcher.update(world.dependencies())?;
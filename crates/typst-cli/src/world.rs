@@ -9,6 +9,7 @@ use ecow::{eco_format, EcoString};
 use parking_lot::Mutex;
 use typst::diag::{FileError, FileResult};
 use typst::foundations::{Bytes, Datetime, Dict, IntoValue};
+use typst::layout::Paper;
 use typst::syntax::{FileId, Source, VirtualPath};
 use typst::text::{Font, FontBook};
 use typst::utils::LazyHash;
@@ -17,7 +18,7 @@ use typst_kit::fonts::{FontSlot, Fonts};
 use typst_kit::package::PackageStorage;
 use typst_timing::timed;
 
-use crate::args::{Feature, Input, ProcessArgs, WorldArgs};
+use crate::args::{DefaultPaper, Feature, Input, ProcessArgs, WorldArgs};
 use crate::download::PrintDownload;
 use crate::package;
 
@@ -120,7 +121,19 @@ impl SystemWorld {
                 })
                 .collect();
 
-            Library::builder().with_inputs(inputs).with_features(features).build()
+            let mut builder =
+                Library::builder().with_inputs(inputs).with_features(features);
+
+            let paper = match world_args.paper {
+                Some(DefaultPaper::Named(paper)) => Some(paper),
+                Some(DefaultPaper::Locale) => locale_paper(),
+                None => None,
+            };
+            if let Some(paper) = paper {
+                builder = builder.with_paper(paper);
+            }
+
+            builder.build()
         };
 
         let fonts = Fonts::searcher()
@@ -434,6 +447,28 @@ fn decode_utf8(buf: &[u8]) -> FileResult<&str> {
     Ok(std::str::from_utf8(buf.strip_prefix(b"\xef\xbb\xbf").unwrap_or(buf))?)
 }
 
+/// Determine the paper size from the system's locale, as given by the
+/// `LC_ALL`, `LC_PAPER`, and `LANG` environment variables.
+///
+/// Returns US Letter for regions that use it and A4 for all others. Returns
+/// `None` if no locale with a region is set.
+fn locale_paper() -> Option<Paper> {
+    /// Regions in which US Letter is the customary paper size.
+    const LETTER_REGIONS: &[&str] = &[
+        "US", "CA", "MX", "CL", "CO", "CR", "DO", "GT", "NI", "PA", "PH", "PR", "SV",
+        "VE",
+    ];
+
+    let locale = ["LC_ALL", "LC_PAPER", "LANG"]
+        .into_iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())?;
+
+    // A locale looks like `language_REGION.encoding@modifier`.
+    let region = locale.split(['.', '@']).next()?.split_once('_')?.1;
+    Some(if LETTER_REGIONS.contains(&region) { Paper::US_LETTER } else { Paper::A4 })
+}
+
 /// The current date and time.
 enum Now {
     /// The date and time if the environment `SOURCE_DATE_EPOCH` is set.
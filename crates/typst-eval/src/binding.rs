@@ -57,6 +57,64 @@ pub(crate) fn destructure(
     })
 }
 
+/// Whether a value has the shape required by a pattern, i.e. whether
+/// [`destructure`] would succeed.
+pub(crate) fn matches(pattern: ast::Pattern, value: &Value) -> bool {
+    match pattern {
+        ast::Pattern::Normal(_) | ast::Pattern::Placeholder(_) => true,
+        ast::Pattern::Parenthesized(parenthesized) => {
+            matches(parenthesized.pattern(), value)
+        }
+        ast::Pattern::Destructuring(destruct) => match value {
+            Value::Array(array) => matches_array(destruct, array),
+            Value::Dict(dict) => matches_dict(destruct, dict),
+            _ => false,
+        },
+    }
+}
+
+fn matches_array(destruct: ast::Destructuring, array: &Array) -> bool {
+    let items = destruct.items().count();
+    let spread = destruct
+        .items()
+        .any(|p| matches!(p, ast::DestructuringItem::Spread(_)));
+
+    let len = array.len();
+    let fits = if spread { len + 1 >= items } else { len == items };
+    if !fits {
+        return false;
+    }
+
+    let mut i = 0;
+    for p in destruct.items() {
+        match p {
+            ast::DestructuringItem::Pattern(pattern) => {
+                if !matches(pattern, &array.as_slice()[i]) {
+                    return false;
+                }
+                i += 1;
+            }
+            ast::DestructuringItem::Spread(_) => i += len + 1 - items,
+            ast::DestructuringItem::Named(_) => return false,
+        }
+    }
+
+    true
+}
+
+fn matches_dict(destruct: ast::Destructuring, dict: &Dict) -> bool {
+    destruct.items().all(|p| match p {
+        ast::DestructuringItem::Pattern(ast::Pattern::Normal(ast::Expr::Ident(
+            ident,
+        ))) => dict.contains(&ident),
+        ast::DestructuringItem::Named(named) => {
+            dict.get(&named.name()).is_ok_and(|v| matches(named.pattern(), v))
+        }
+        ast::DestructuringItem::Spread(_) => true,
+        ast::DestructuringItem::Pattern(_) => false,
+    })
+}
+
 /// Destruct the given value into the pattern and apply the function to each binding.
 fn destructure_impl<F>(
     vm: &mut Vm,
@@ -529,6 +529,32 @@ impl<'a> CapturesVisitor<'a> {
                 self.internal.exit();
             }
 
+            // Each arm of a match expression may contain bindings in its
+            // pattern. These are active in the arm's guard and body.
+            Some(ast::Expr::Match(expr)) => {
+                self.visit(expr.scrutinee().to_untyped());
+                for arm in expr.arms() {
+                    self.internal.enter();
+
+                    match arm.pattern() {
+                        ast::MatchPattern::Wildcard => {}
+                        ast::MatchPattern::Binding(pattern) => {
+                            for ident in pattern.bindings() {
+                                self.bind(ident);
+                            }
+                        }
+                        ast::MatchPattern::Value(expr) => self.visit(expr.to_untyped()),
+                    }
+
+                    if let Some(guard) = arm.guard() {
+                        self.visit(guard.to_untyped());
+                    }
+
+                    self.visit(arm.body().to_untyped());
+                    self.internal.exit();
+                }
+            }
+
             // An import contains items, but these are active only after the
             // path is evaluated.
             Some(ast::Expr::Import(expr)) => {
@@ -133,6 +133,7 @@ impl Eval for ast::Expr<'_> {
             Self::Conditional(v) => v.eval(vm),
             Self::While(v) => v.eval(vm),
             Self::For(v) => v.eval(vm),
+            Self::Match(v) => v.eval(vm),
             Self::Import(v) => v.eval(vm),
             Self::Include(v) => v.eval(vm).map(Value::Content),
             Self::Break(v) => v.eval(vm),
@@ -1,10 +1,10 @@
 use typst_library::diag::{bail, error, At, SourceDiagnostic, SourceResult};
-use typst_library::foundations::{ops, IntoValue, Value};
+use typst_library::foundations::{ops, IntoValue, Repr, Value};
 use typst_syntax::ast::{self, AstNode};
 use typst_syntax::{Span, SyntaxKind, SyntaxNode};
 use unicode_segmentation::UnicodeSegmentation;
 
-use crate::{destructure, Eval, Vm};
+use crate::{destructure, matches, Eval, Vm};
 
 /// The maximum number of loop iterations.
 const MAX_ITERATIONS: usize = 10_000;
@@ -60,6 +60,63 @@ impl Eval for ast::Conditional<'_> {
     }
 }
 
+impl Eval for ast::MatchExpr<'_> {
+    type Output = Value;
+
+    fn eval(self, vm: &mut Vm) -> SourceResult<Self::Output> {
+        let scrutinee = self.scrutinee();
+        let value = scrutinee.eval(vm)?;
+
+        for arm in self.arms() {
+            vm.scopes.enter();
+            let output = eval_match_arm(vm, arm, &value);
+            vm.scopes.exit();
+            if let Some(output) = output? {
+                // Mark the return as conditional.
+                if let Some(FlowEvent::Return(_, _, conditional)) = &mut vm.flow {
+                    *conditional = true;
+                }
+                return Ok(output);
+            }
+        }
+
+        bail!(
+            scrutinee.span(), "no match arm matched {}", value.repr();
+            hint: "add a `_` arm to handle all other values"
+        )
+    }
+}
+
+/// Evaluates a match arm's body if its pattern and guard match the value.
+fn eval_match_arm(
+    vm: &mut Vm,
+    arm: ast::MatchArm,
+    value: &Value,
+) -> SourceResult<Option<Value>> {
+    match arm.pattern() {
+        ast::MatchPattern::Wildcard => {}
+        ast::MatchPattern::Binding(pattern) => {
+            if !matches(pattern, value) {
+                return Ok(None);
+            }
+            destructure(vm, pattern, value.clone())?;
+        }
+        ast::MatchPattern::Value(expr) => {
+            if !ops::equal(&expr.eval(vm)?, value) {
+                return Ok(None);
+            }
+        }
+    }
+
+    if let Some(guard) = arm.guard() {
+        if !guard.eval(vm)?.cast::<bool>().at(guard.span())? {
+            return Ok(None);
+        }
+    }
+
+    arm.body().eval(vm).map(Some)
+}
+
 impl Eval for ast::WhileLoop<'_> {
     type Output = Value;
 
@@ -96,11 +96,14 @@ pub fn eval(
 
 /// Evaluate a string as code and return the resulting value.
 ///
-/// Everything in the output is associated with the given `span`.
+/// Everything in the output is associated with the given `span`. The `route`
+/// is extended so that nested evaluations count towards the maximum call
+/// depth.
 #[comemo::memoize]
 pub fn eval_string(
     routines: &Routines,
     world: Tracked<dyn World + '_>,
+    route: Tracked<Route>,
     string: &str,
     span: Span,
     mode: EvalMode,
@@ -130,7 +133,7 @@ pub fn eval_string(
         introspector: introspector.track(),
         traced: traced.track(),
         sink: sink.track_mut(),
-        route: Route::default(),
+        route: Route::extend(route),
     };
 
     // Prepare VM.
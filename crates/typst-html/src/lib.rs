@@ -4,15 +4,18 @@ mod encode;
 
 pub use self::encode::html;
 
+use std::collections::HashSet;
+
 use comemo::{Track, Tracked, TrackedMut};
+use ecow::EcoString;
 use typst_library::diag::{bail, warning, At, SourceResult};
 use typst_library::engine::{Engine, Route, Sink, Traced};
 use typst_library::foundations::{Content, StyleChain, Target, TargetElem};
 use typst_library::html::{
-    attr, tag, FrameElem, HtmlDocument, HtmlElem, HtmlElement, HtmlNode,
+    attr, label_id, tag, FrameElem, HtmlDocument, HtmlElem, HtmlElement, HtmlNode,
 };
 use typst_library::introspection::{
-    Introspector, Locator, LocatorLink, SplitLocator, TagElem,
+    Introspector, Locator, LocatorLink, SplitLocator, Tag, TagElem,
 };
 use typst_library::layout::{Abs, Axes, BoxElem, Region, Size};
 use typst_library::model::{DocumentInfo, ParElem};
@@ -82,7 +85,8 @@ fn html_document_impl(
         styles,
     )?;
 
-    let output = handle_list(&mut engine, &mut locator, children.iter().copied())?;
+    let mut output = handle_list(&mut engine, &mut locator, children.iter().copied())?;
+    assign_label_ids(&mut output, &mut HashSet::new());
     let root = root_element(output, &info)?;
     let introspector = Introspector::html(&root);
 
@@ -236,6 +240,48 @@ fn handle(
     Ok(())
 }
 
+/// Give elements produced by labelled content an `id` attribute so that
+/// links to the label can navigate to them.
+///
+/// The `id` is derived from the label with [`label_id`], which is also used
+/// for the `href` of links to the label. If labelled content does not start
+/// with an element, an empty `<span>` is inserted to carry the `id`. Should a
+/// label occur multiple times, only its first occurrence receives the `id`.
+fn assign_label_ids(nodes: &mut Vec<HtmlNode>, used: &mut HashSet<EcoString>) {
+    let mut pending = None;
+    let mut i = 0;
+    while i < nodes.len() {
+        match &mut nodes[i] {
+            HtmlNode::Tag(Tag::Start(elem)) => {
+                if let Some(label) = elem.label() {
+                    pending = Some((i + 1, label_id(label)));
+                }
+            }
+            HtmlNode::Tag(Tag::End(..)) => {}
+            HtmlNode::Element(element) => {
+                if let Some((_, id)) = pending.take() {
+                    if !element.attrs.0.iter().any(|(attr, _)| *attr == attr::id)
+                        && used.insert(id.clone())
+                    {
+                        element.attrs.push(attr::id, id);
+                    }
+                }
+                assign_label_ids(&mut element.children, used);
+            }
+            HtmlNode::Text(..) | HtmlNode::Frame(_) => {
+                if let Some((at, id)) = pending.take() {
+                    if used.insert(id.clone()) {
+                        let anchor = HtmlElement::new(tag::span).with_attr(attr::id, id);
+                        nodes.insert(at, anchor.into());
+                        i += 1;
+                    }
+                }
+            }
+        }
+        i += 1;
+    }
+}
+
 /// Wrap the nodes in `<html>` and `<body>` if they are not yet rooted,
 /// supplying a suitable `<head>`.
 fn root_element(output: Vec<HtmlNode>, info: &DocumentInfo) -> SourceResult<HtmlElement> {
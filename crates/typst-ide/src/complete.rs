@@ -1058,6 +1058,12 @@ fn code_completions(ctx: &mut CompletionContext, hash: bool) {
             "(${params}) => ${output}",
             "Creates an unnamed function.",
         );
+
+        ctx.snippet_completion(
+            "match expression",
+            "match ${value} {\n\t${pattern}: ${output},\n\t_: ${},\n}",
+            "Computes or inserts something based on the shape of a value.",
+        );
     }
 }
 
@@ -1694,6 +1700,18 @@ mod tests {
             .must_exclude(["nums", "a", "b"]);
     }
 
+    /// Test that local bindings are only completed where they are in scope.
+    #[test]
+    fn test_autocomplete_local_scope() {
+        test("#let outer = 1; #o", -1).must_include(["outer"]);
+        test("#{ let inner = 2; i }", -2).must_include(["inner", "int"]);
+        test("#{ let inner = 2 }; #i", -1)
+            .must_include(["int"])
+            .must_exclude(["inner"]);
+        test("#let f(param) = p", -1).must_include(["param"]);
+        test("#let d = (key: 1); #d.", -1).must_include(["key"]);
+    }
+
     #[test]
     fn test_autocomplete_packages() {
         test("#import \"@\"", -2).must_include([q!("@preview/example:0.1.0")]);
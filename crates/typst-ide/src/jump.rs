@@ -1,8 +1,10 @@
 use std::num::NonZeroUsize;
+use std::ops::Range;
 
 use typst::layout::{Frame, FrameItem, PagedDocument, Point, Position, Size};
 use typst::model::{Destination, Url};
 use typst::syntax::{FileId, LinkedNode, Side, Source, Span, SyntaxKind};
+use typst::text::Glyph;
 use typst::visualize::Geometry;
 use typst::WorldExt;
 
@@ -108,6 +110,30 @@ pub fn jump_from_click(
     None
 }
 
+/// Find the range in the source code that a glyph was shaped from.
+///
+/// For glyphs from markup text, this is the exact range of the glyph's
+/// characters, which spans multiple characters for ligatures and clusters.
+/// For other glyphs (e.g. from strings in code), it is the range of the
+/// syntax node that produced them.
+pub fn glyph_source_range(
+    world: &dyn IdeWorld,
+    glyph: &Glyph,
+) -> Option<(FileId, Range<usize>)> {
+    let (span, span_offset) = glyph.span;
+    let id = span.id()?;
+    let source = world.source(id).ok()?;
+    let node = source.find(span)?;
+    let range = node.range();
+    if node.kind() != SyntaxKind::Text {
+        return Some((id, range));
+    }
+
+    let start = (range.start + usize::from(span_offset)).min(range.end);
+    let end = (start + glyph.range().len()).min(range.end);
+    Some((id, start..end))
+}
+
 /// Find the output location in the document for a cursor position.
 pub fn jump_from_cursor(
     document: &PagedDocument,
@@ -185,9 +211,9 @@ mod tests {
     use std::borrow::Borrow;
     use std::num::NonZeroUsize;
 
-    use typst::layout::{Abs, Point, Position};
+    use typst::layout::{Abs, FrameItem, Point, Position};
 
-    use super::{jump_from_click, jump_from_cursor, Jump};
+    use super::{glyph_source_range, jump_from_click, jump_from_cursor, Jump};
     use crate::tests::{FilePos, TestWorld, WorldLike};
 
     fn point(x: f64, y: f64) -> Point {
@@ -243,6 +269,31 @@ mod tests {
         }
     }
 
+    #[track_caller]
+    fn test_glyph_ranges(world: impl WorldLike, expected: &[std::ops::Range<usize>]) {
+        let world = world.acquire();
+        let world = world.borrow();
+        let doc = typst::compile(world).output.unwrap();
+        let mut ranges = vec![];
+        doc.pages[0].frame.walk(|_, item| {
+            if let FrameItem::Text(text) = item {
+                for glyph in &text.glyphs {
+                    let (id, range) = glyph_source_range(world, glyph).unwrap();
+                    assert_eq!(id, TestWorld::main_id());
+                    ranges.push(range);
+                }
+            }
+        });
+        assert_eq!(ranges, expected);
+    }
+
+    #[test]
+    fn test_glyph_source_range() {
+        test_glyph_ranges("Hey", &[0..1, 1..2, 2..3]);
+        test_glyph_ranges("#[Hi]", &[2..3, 3..4]);
+        test_glyph_ranges("#\"Hi\"", &[1..5, 1..5]);
+    }
+
     #[test]
     fn test_jump_from_click() {
         let s = "*Hello* #box[ABC] World";
@@ -5,14 +5,16 @@ mod complete;
 mod definition;
 mod jump;
 mod matchers;
+mod signature;
 mod tooltip;
 mod utils;
 
 pub use self::analyze::{analyze_expr, analyze_import, analyze_labels};
 pub use self::complete::{autocomplete, Completion, CompletionKind};
 pub use self::definition::{definition, Definition};
-pub use self::jump::{jump_from_click, jump_from_cursor, Jump};
+pub use self::jump::{glyph_source_range, jump_from_click, jump_from_cursor, Jump};
 pub use self::matchers::{deref_target, named_items, DerefTarget, NamedItem};
+pub use self::signature::{signature_help, SignatureHelp, SignatureParam};
 pub use self::tooltip::{tooltip, Tooltip};
 
 use ecow::EcoString;
@@ -129,6 +129,18 @@ pub fn named_items<T>(
                 }
             }
 
+            if let Some(v) = parent.cast::<ast::MatchArm>() {
+                if let ast::MatchPattern::Binding(pattern) = v.pattern() {
+                    if node.prev_sibling_kind() != Some(SyntaxKind::Let) {
+                        for ident in pattern.bindings() {
+                            if let Some(res) = recv(NamedItem::Var(ident)) {
+                                return Some(res);
+                            }
+                        }
+                    }
+                }
+            }
+
             if let Some(v) = parent.cast::<ast::Closure>().filter(|v| {
                 // Check if the node is in the body of the closure.
                 let body = parent.find(v.body().span());
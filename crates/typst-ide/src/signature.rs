@@ -0,0 +1,253 @@
+use ecow::{eco_format, EcoString};
+use typst::foundations::{Func, ParamInfo, Value};
+use typst::syntax::{ast, LinkedNode, Side, Source, SyntaxKind};
+
+use crate::utils::{globals, plain_docs_sentence};
+use crate::{analyze_expr, IdeWorld};
+
+/// Describe the signature of the function call around the cursor.
+///
+/// Signature help is only available for native functions and elements, whose
+/// parameters are known. The active parameter is determined from the argument
+/// the cursor is in or, between arguments, the next one to be written.
+pub fn signature_help(
+    world: &dyn IdeWorld,
+    source: &Source,
+    cursor: usize,
+) -> Option<SignatureHelp> {
+    let leaf = LinkedNode::new(source.root()).leaf_at(cursor, Side::Before)?;
+    let args = find_args(leaf, cursor)?;
+    let func = resolve_callee(world, &args)?;
+    let params = func.params()?;
+
+    let name = func.name().unwrap_or_default();
+    let label = eco_format!(
+        "{name}({})",
+        params.iter().map(param_label).collect::<Vec<_>>().join(", ")
+    );
+
+    Some(SignatureHelp {
+        label,
+        docs: func.docs().map(plain_docs_sentence),
+        params: params
+            .iter()
+            .map(|param| SignatureParam {
+                label: param_label(param),
+                docs: plain_docs_sentence(param.docs),
+            })
+            .collect(),
+        active: active_param(params, &args, cursor),
+    })
+}
+
+/// Signature help for a function call.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignatureHelp {
+    /// The function's signature, e.g. `rect(body, width:, ..)`.
+    pub label: EcoString,
+    /// A summary of the function's documentation.
+    pub docs: Option<EcoString>,
+    /// The function's parameters in order of their definition.
+    pub params: Vec<SignatureParam>,
+    /// The index of the parameter the cursor is at, if any.
+    pub active: Option<usize>,
+}
+
+/// A parameter in a [`SignatureHelp`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignatureParam {
+    /// The parameter as it appears in the signature label.
+    pub label: EcoString,
+    /// A summary of the parameter's documentation.
+    pub docs: EcoString,
+}
+
+/// Find the innermost argument list whose parentheses enclose the cursor.
+fn find_args(leaf: LinkedNode, cursor: usize) -> Option<LinkedNode> {
+    let mut node = leaf;
+    loop {
+        if node.kind() == SyntaxKind::Args {
+            let mut children = node.children();
+            let open = children.find(|c| c.kind() == SyntaxKind::LeftParen);
+            let close = children.find(|c| c.kind() == SyntaxKind::RightParen);
+            if open.is_some_and(|open| open.offset() < cursor)
+                && close.map_or(true, |close| cursor <= close.offset())
+            {
+                return Some(node);
+            }
+        }
+        node = node.parent()?.clone();
+    }
+}
+
+/// Determine the function that is called with the given arguments.
+fn resolve_callee(world: &dyn IdeWorld, args: &LinkedNode) -> Option<Func> {
+    let parent = args.parent()?;
+    let callee = match parent.cast::<ast::Expr>()? {
+        ast::Expr::FuncCall(call) => call.callee(),
+        ast::Expr::Set(set) => set.target(),
+        _ => return None,
+    };
+
+    let callee = parent.find(callee.span())?;
+    let name = match callee.cast::<ast::Expr>()? {
+        ast::Expr::Ident(ident) => Some(ident.get().clone()),
+        ast::Expr::MathIdent(ident) => Some(ident.get().clone()),
+        _ => None,
+    };
+
+    if let Some(Value::Func(func)) =
+        name.and_then(|name| globals(world, &callee).get(&name))
+    {
+        return Some(func.clone());
+    }
+
+    analyze_expr(world, &callee)
+        .into_iter()
+        .find_map(|(value, _)| match value {
+            Value::Func(func) => Some(func),
+            _ => None,
+        })
+}
+
+/// Determine the parameter the cursor is at.
+fn active_param(params: &[ParamInfo], args: &LinkedNode, cursor: usize) -> Option<usize> {
+    // The number of positional arguments before the one at the cursor and
+    // whether we are in a positional or named argument.
+    let mut positional = 0;
+    let mut in_positional = false;
+    let mut named: Option<EcoString> = None;
+
+    for child in args.children() {
+        if child.kind() == SyntaxKind::RightParen || child.offset() >= cursor {
+            break;
+        }
+
+        match child.kind() {
+            SyntaxKind::Comma => {
+                positional += usize::from(in_positional);
+                in_positional = false;
+                named = None;
+            }
+            SyntaxKind::Named => {
+                named = child.cast::<ast::Named>().map(|n| n.name().get().clone());
+            }
+            SyntaxKind::Spread => {}
+            _ if child.is::<ast::Expr>() => in_positional = true,
+            _ => {}
+        }
+    }
+
+    if let Some(name) = named {
+        return params.iter().position(|param| param.name == name.as_str());
+    }
+
+    let mut seen = 0;
+    for (i, param) in params.iter().enumerate().filter(|(_, param)| param.positional) {
+        if param.variadic || seen == positional {
+            return Some(i);
+        }
+        seen += 1;
+    }
+
+    None
+}
+
+/// How a parameter appears in a signature label.
+fn param_label(param: &ParamInfo) -> EcoString {
+    if param.variadic {
+        eco_format!("..{}", param.name)
+    } else if param.positional {
+        param.name.into()
+    } else {
+        eco_format!("{}:", param.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Borrow;
+
+    use super::{signature_help, SignatureHelp};
+    use crate::tests::{FilePos, WorldLike};
+
+    type Response = Option<SignatureHelp>;
+
+    trait ResponseExt {
+        fn must_be_none(&self) -> &Self;
+        fn must_have_label(&self, label: &str) -> &Self;
+        fn must_have_active(&self, name: Option<&str>) -> &Self;
+    }
+
+    impl ResponseExt for Response {
+        #[track_caller]
+        fn must_be_none(&self) -> &Self {
+            assert_eq!(*self, None);
+            self
+        }
+
+        #[track_caller]
+        fn must_have_label(&self, label: &str) -> &Self {
+            let help = self.as_ref().expect("no signature help");
+            assert!(
+                help.label.starts_with(label),
+                "{:?} does not start with {label:?}",
+                help.label,
+            );
+            self
+        }
+
+        #[track_caller]
+        fn must_have_active(&self, name: Option<&str>) -> &Self {
+            let help = self.as_ref().expect("no signature help");
+            let active = help.active.map(|i| {
+                help.params[i].label.trim_start_matches("..").trim_end_matches(':')
+            });
+            assert_eq!(active, name);
+            self
+        }
+    }
+
+    #[track_caller]
+    fn test(world: impl WorldLike, pos: impl FilePos) -> Response {
+        let world = world.acquire();
+        let world = world.borrow();
+        let (source, cursor) = pos.resolve(world);
+        signature_help(world, &source, cursor)
+    }
+
+    #[test]
+    fn test_signature_help() {
+        test("#numbering()", -2)
+            .must_have_label("numbering(numbering, ..numbers)")
+            .must_have_active(Some("numbering"));
+        test("#numbering(\"1\", )", -2).must_have_active(Some("numbers"));
+        test("#numbering(\"1\", 1, 2, )", -2).must_have_active(Some("numbers"));
+    }
+
+    #[test]
+    fn test_signature_help_named() {
+        test("#rect(width: 1pt)", -5)
+            .must_have_label("rect(")
+            .must_have_active(Some("width"));
+        test("#set text(size: )", -2).must_have_active(Some("size"));
+    }
+
+    #[test]
+    fn test_signature_help_outside_parens() {
+        test("#rect()", 5).must_be_none();
+        test("#rect()", -1).must_be_none();
+        test("#rect()[Hello]", -2).must_be_none();
+    }
+
+    #[test]
+    fn test_signature_help_nested() {
+        test("#rect(fill: rgb())", -3).must_have_label("rgb(");
+        test("#rect(fill: rgb(), )", -2).must_have_label("rect(");
+    }
+
+    #[test]
+    fn test_signature_help_local() {
+        test("#let f = rect; #f(width: 1pt)", -5).must_have_active(Some("width"));
+    }
+}
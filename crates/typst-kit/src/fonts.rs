@@ -7,15 +7,38 @@
 //! - For text: Libertinus Serif, New Computer Modern
 //! - For math: New Computer Modern Math
 //! - For code: Deja Vu Sans Mono
+//!
+//! # Shared font cache
+//! With the `shared-font-cache` feature flag, fonts are decoded through a
+//! process-wide cache keyed by their content instead of their path. This way,
+//! a font file that is found by multiple [`FontSearcher`]s (e.g. in a server
+//! that compiles many documents with separate worlds) is only held in memory
+//! once. The cache only holds weak references, so a font is freed once no
+//! [`Fonts`] instance that loaded it is alive anymore.
+//!
+//! Images need no such cache: Decoding them is memoized by content, so an
+//! image shared by multiple documents is already only decoded once until the
+//! memoization cache is evicted with `comemo::evict`.
 
+#[cfg(feature = "shared-font-cache")]
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
+#[cfg(feature = "shared-font-cache")]
+use std::sync::{LazyLock, Mutex, Weak};
 
 use fontdb::{Database, Source};
+use typst_library::foundations::Bytes;
 use typst_library::text::{Font, FontBook, FontInfo};
 use typst_timing::TimingScope;
 
+/// Decoded fonts shared across all font slots, keyed by the hash of their data
+/// and their index in the collection.
+#[cfg(feature = "shared-font-cache")]
+static SHARED_FONTS: LazyLock<Mutex<HashMap<u128, Weak<Font>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
 /// Holds details about the location of a font and lazily the font itself.
 #[derive(Debug)]
 pub struct FontSlot {
@@ -25,7 +48,7 @@ pub struct FontSlot {
     /// to a collection.
     index: u32,
     /// The lazily loaded font.
-    font: OnceLock<Option<Font>>,
+    font: OnceLock<Option<Arc<Font>>>,
 }
 
 impl FontSlot {
@@ -54,10 +77,35 @@ impl FontSlot {
                 )
                 .ok()?
                 .into();
-                Font::new(data, self.index)
+                load_font(data, self.index)
             })
-            .clone()
+            .as_deref()
+            .cloned()
+    }
+}
+
+/// Decode a font, reusing an already decoded font with the same content that
+/// is still alive.
+#[cfg(feature = "shared-font-cache")]
+fn load_font(data: Bytes, index: u32) -> Option<Arc<Font>> {
+    let key = typst_utils::hash128(&(&data, index));
+    let mut fonts = SHARED_FONTS.lock().unwrap();
+    if let Some(font) = fonts.get(&key).and_then(Weak::upgrade) {
+        return Some(font);
     }
+
+    // Drop entries of fonts that were freed in the meantime.
+    fonts.retain(|_, font| font.strong_count() > 0);
+
+    let font = Arc::new(Font::new(data, index)?);
+    fonts.insert(key, Arc::downgrade(&font));
+    Some(font)
+}
+
+/// Decode a font.
+#[cfg(not(feature = "shared-font-cache"))]
+fn load_font(data: Bytes, index: u32) -> Option<Arc<Font>> {
+    Font::new(data, index).map(Arc::new)
 }
 
 /// The result of a font search, created by calling [`FontSearcher::search`].
@@ -74,6 +122,77 @@ impl Fonts {
     pub fn searcher() -> FontSearcher {
         FontSearcher::new()
     }
+
+    /// Register all fonts contained in the given data, for example a font
+    /// file that is bundled with the executable. For font collections, all
+    /// fonts in the collection are registered.
+    ///
+    /// Returns metadata about the registered fonts, which is empty if the data
+    /// does not contain any valid font.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use typst_kit::fonts::FontSearcher;
+    /// # use typst_library::foundations::Bytes;
+    /// let mut fonts = FontSearcher::new().search();
+    /// let data = std::fs::read("./assets/fonts/Body.otf").unwrap();
+    /// for info in fonts.register(Bytes::from(data)) {
+    ///     println!("Registered {} ({:?})", info.family, info.variant);
+    /// }
+    /// ```
+    pub fn register(&mut self, data: Bytes) -> Vec<FontInfo> {
+        let mut infos = vec![];
+        for (i, font) in Font::iter(data).enumerate() {
+            let info = font.info().clone();
+            self.book.push(info.clone());
+            self.fonts.push(FontSlot {
+                path: None,
+                index: i as u32,
+                font: OnceLock::from(Some(Arc::new(font))),
+            });
+            infos.push(info);
+        }
+        infos
+    }
+
+    /// Load all fonts into memory ahead of time.
+    ///
+    /// Fonts are normally loaded lazily when a document first uses them, which
+    /// slows down the first compilation. A long-running process like a server
+    /// can call this at startup to pay that cost up front. Fonts that fail to
+    /// load do not stop the others from loading.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use typst_kit::fonts::FontSearcher;
+    /// let fonts = FontSearcher::new().search();
+    /// let preloaded = fonts.preload();
+    /// println!("Loaded {} fonts", preloaded.loaded.len());
+    /// for slot in preloaded.failed {
+    ///     eprintln!("Failed to load {:?}", slot.path());
+    /// }
+    /// ```
+    pub fn preload(&self) -> Preloaded<'_> {
+        let _scope = TimingScope::new("preload fonts");
+        let mut preloaded = Preloaded::default();
+        for (i, slot) in self.fonts.iter().enumerate() {
+            match (slot.get(), self.book.info(i)) {
+                (Some(_), Some(info)) => preloaded.loaded.push(info),
+                _ => preloaded.failed.push(slot),
+            }
+        }
+        preloaded
+    }
+}
+
+/// The result of loading all fonts ahead of time, created by calling
+/// [`Fonts::preload`].
+#[derive(Debug, Default)]
+pub struct Preloaded<'a> {
+    /// Metadata about the fonts that were loaded.
+    pub loaded: Vec<&'a FontInfo>,
+    /// The slots of the fonts that could not be loaded.
+    pub failed: Vec<&'a FontSlot>,
 }
 
 /// Searches for fonts.
@@ -90,6 +209,7 @@ pub struct FontSearcher {
     include_embedded_fonts: bool,
     book: FontBook,
     fonts: Vec<FontSlot>,
+    aliases: Vec<(String, Vec<String>)>,
 }
 
 impl FontSearcher {
@@ -103,6 +223,7 @@ impl FontSearcher {
             include_embedded_fonts: true,
             book: FontBook::new(),
             fonts: vec![],
+            aliases: vec![],
         }
     }
 
@@ -119,6 +240,30 @@ impl FontSearcher {
         self
     }
 
+    /// Define an alias for a font family, e.g. to map a logical family name
+    /// used by documents to a font installed on the system.
+    ///
+    /// The alias resolves to the first of the given families that is found.
+    /// If none of them is found, the alias remains undefined, so that using it
+    /// results in the usual unknown font family warning and fallback.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use typst_kit::fonts::FontSearcher;
+    /// let fonts = FontSearcher::new()
+    ///     .alias("Body", ["Libertinus Serif", "New Computer Modern"])
+    ///     .search();
+    /// ```
+    pub fn alias<I, S>(&mut self, name: impl Into<String>, families: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.aliases
+            .push((name.into(), families.into_iter().map(Into::into).collect()));
+        self
+    }
+
     /// Start searching for and loading fonts. To additionally load fonts
     /// from specific directories, use [`search_with`][Self::search_with].
     ///
@@ -186,6 +331,11 @@ impl FontSearcher {
             self.add_embedded();
         }
 
+        for (name, families) in std::mem::take(&mut self.aliases) {
+            let families: Vec<_> = families.iter().map(String::as_str).collect();
+            self.book.alias(&name, &families);
+        }
+
         Fonts {
             book: std::mem::take(&mut self.book),
             fonts: std::mem::take(&mut self.fonts),
@@ -202,7 +352,7 @@ impl FontSearcher {
                 self.fonts.push(FontSlot {
                     path: None,
                     index: i as u32,
-                    font: OnceLock::from(Some(font)),
+                    font: OnceLock::from(Some(Arc::new(font))),
                 });
             }
         }
@@ -214,3 +364,63 @@ impl Default for FontSearcher {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preload() {
+        let mut fonts = FontSearcher::new().include_system_fonts(false).search();
+        for data in typst_dev_assets::fonts() {
+            fonts.register(Bytes::from_static(data));
+        }
+
+        let info = fonts.book.info(0).unwrap().clone();
+        fonts.book.push(info);
+        fonts.fonts.push(FontSlot {
+            path: Some("does-not-exist.ttf".into()),
+            index: 0,
+            font: OnceLock::new(),
+        });
+
+        let preloaded = fonts.preload();
+        assert_eq!(preloaded.loaded.len(), fonts.fonts.len() - 1);
+        assert_eq!(preloaded.failed.len(), 1);
+        assert_eq!(preloaded.failed[0].path(), Some(Path::new("does-not-exist.ttf")));
+    }
+
+    #[test]
+    #[cfg(feature = "shared-font-cache")]
+    fn test_shared_font_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let data = typst_dev_assets::fonts().next().unwrap();
+        fs::write(dir.path().join("font.ttf"), data).unwrap();
+
+        let search = || {
+            FontSearcher::new()
+                .include_system_fonts(false)
+                .search_with([dir.path()])
+        };
+        let loaded = |fonts: &Fonts| {
+            fonts.fonts[0].get().unwrap();
+            fonts.fonts[0].font.get().unwrap().clone().unwrap()
+        };
+
+        // Two searchers decode the font only once.
+        let (a, b) = (search(), search());
+        let font = loaded(&a);
+        assert!(Arc::ptr_eq(&font, &loaded(&b)));
+
+        // Once no searcher holds the font anymore, it is freed.
+        let weak = Arc::downgrade(&font);
+        drop((a, b, font));
+        assert!(weak.upgrade().is_none());
+
+        // Loading it again decodes it anew and drops the stale cache entry.
+        let c = search();
+        loaded(&c);
+        let fonts = SHARED_FONTS.lock().unwrap();
+        assert!(fonts.values().all(|font| font.strong_count() > 0));
+    }
+}
@@ -23,6 +23,12 @@ use typst_library::World;
 
 use super::{layout_multi_block, layout_single_block};
 
+/// The weakness of the automatic spacing around paragraphs and blocks.
+///
+/// This spacing stems from `par.spacing` and the `above` and `below` defaults
+/// of blocks, as opposed to spacing that was set explicitly.
+pub const AUTO_SPACING: u8 = 4;
+
 /// Collects all elements of the flow into prepared children. These are much
 /// simpler to handle than the raw elements.
 #[typst_macros::time]
@@ -43,6 +49,7 @@ pub fn collect<'a>(
         expand,
         output: Vec::with_capacity(children.len()),
         last_was_par: false,
+        keep_lines: 0,
     }
     .run()
 }
@@ -57,6 +64,9 @@ struct Collector<'a, 'x, 'y> {
     locator: SplitLocator<'a>,
     output: Vec<Child<'a>>,
     last_was_par: bool,
+    /// How many lines of the next paragraph must be kept together with a
+    /// preceding sticky block.
+    keep_lines: usize,
 }
 
 impl<'a> Collector<'a, '_, '_> {
@@ -125,7 +135,7 @@ impl<'a> Collector<'a, '_, '_> {
         )?
         .into_frames();
 
-        self.output.push(Child::Rel(spacing.into(), 4));
+        self.output.push(Child::Rel(spacing.into(), AUTO_SPACING));
 
         // Determine whether to prevent widow and orphans.
         let len = lines.len();
@@ -135,6 +145,11 @@ impl<'a> Collector<'a, '_, '_> {
             costs.widow() > Ratio::zero() && len >= 2 && !lines[len - 2].is_empty();
         let prevent_all = len == 3 && prevent_orphans && prevent_widows;
 
+        // Determine how many lines must be kept with a preceding sticky block.
+        let keep = std::mem::take(&mut self.keep_lines).min(len);
+        let kept = lines[..keep].iter().map(Frame::height).sum::<Abs>()
+            + leading * keep.saturating_sub(1) as f64;
+
         // Store the heights of lines at the edges because we'll potentially
         // need these later when `lines` is already moved.
         let height_at = |i| lines.get(i).map(Frame::height).unwrap_or_default();
@@ -152,7 +167,7 @@ impl<'a> Collector<'a, '_, '_> {
             // - all lines if it's just three
             // - the first two lines if we're at the first line
             // - the last two lines if we're at the second to last line
-            let need = if prevent_all && i == 0 {
+            let mut need = if prevent_all && i == 0 {
                 front_1 + leading + front_2 + leading + back_1
             } else if prevent_orphans && i == 0 {
                 front_1 + leading + front_2
@@ -162,11 +177,17 @@ impl<'a> Collector<'a, '_, '_> {
                 frame.height()
             };
 
+            // Additionally, the first line requires enough space for all lines
+            // that are kept with a preceding sticky block.
+            if i == 0 {
+                need.set_max(kept);
+            }
+
             self.output
                 .push(Child::Line(self.boxed(LineChild { frame, align, need })));
         }
 
-        self.output.push(Child::Rel(spacing.into(), 4));
+        self.output.push(Child::Rel(spacing.into(), AUTO_SPACING));
         self.last_was_par = true;
 
         Ok(())
@@ -180,6 +201,8 @@ impl<'a> Collector<'a, '_, '_> {
         let alone = self.children.len() == 1;
         let sticky = elem.sticky(styles);
         let breakable = elem.breakable(styles);
+        self.keep_lines =
+            if sticky { BlockElem::sticky_lines_in(styles).get() } else { 0 };
         let fr = match elem.height(styles) {
             Sizing::Fr(fr) => Some(fr),
             _ => None,
@@ -187,7 +210,7 @@ impl<'a> Collector<'a, '_, '_> {
 
         let fallback = LazyCell::new(|| ParElem::spacing_in(styles));
         let spacing = |amount| match amount {
-            Smart::Auto => Child::Rel((*fallback).into(), 4),
+            Smart::Auto => Child::Rel((*fallback).into(), AUTO_SPACING),
             Smart::Custom(Spacing::Rel(rel)) => Child::Rel(rel.resolve(styles), 3),
             Smart::Custom(Spacing::Fr(fr)) => Child::Fr(fr),
         };
@@ -14,6 +14,7 @@ use typst_library::layout::{
 use typst_library::model::{
     FootnoteElem, FootnoteEntry, LineNumberingScope, Numbering, ParLineMarker,
 };
+use typst_library::visualize::{FixedStroke, Geometry};
 use typst_syntax::Span;
 use typst_utils::{NonZeroExt, Numeric};
 
@@ -134,6 +135,7 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
         let mut output = Frame::hard(size);
         let mut offset = Abs::zero();
         let mut locator = locator.split();
+        let mut separators = vec![];
 
         // Lay out the columns and stitch them together.
         for i in 0..self.config.columns.count {
@@ -144,6 +146,14 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
                 output.size_mut().y.set_max(frame.height());
             }
 
+            // Remember where to draw a rule in the gutter before this column,
+            // unless the column stayed empty. Introspection tags don't count
+            // as content since they are invisible.
+            if i > 0 && frame.items().any(|(_, item)| !matches!(item, FrameItem::Tag(_)))
+            {
+                separators.push(offset - self.config.columns.gutter / 2.0);
+            }
+
             let width = frame.width();
             let x = if self.config.columns.dir == Dir::LTR {
                 offset
@@ -156,9 +166,31 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
             inner.next();
         }
 
+        if let Some(stroke) = &self.config.columns.separator {
+            self.column_separators(&mut output, stroke, &separators, regions.size.x);
+        }
+
         Ok(output)
     }
 
+    /// Draws vertical rules at the given offsets from the start of the column
+    /// area, spanning the full height of the stitched columns.
+    fn column_separators(
+        &self,
+        output: &mut Frame,
+        stroke: &FixedStroke,
+        offsets: &[Abs],
+        width: Abs,
+    ) {
+        let height = output.height();
+        for &offset in offsets {
+            let x =
+                if self.config.columns.dir == Dir::LTR { offset } else { width - offset };
+            let shape = Geometry::Line(Point::with_y(height)).stroked(stroke.clone());
+            output.push(Point::with_x(x), FrameItem::Shape(shape, Span::detached()));
+        }
+    }
+
     /// Lay out a column, including column insertions.
     fn column(&mut self, locator: Locator, regions: Regions) -> FlowResult<Frame> {
         // Reset column insertion when starting a new column.
@@ -908,7 +940,7 @@ fn layout_line_number(
 /// until we reach a tag, at which point we add the tag's position and finish.
 /// That gives us the absolute height of the tag from the start of the root
 /// frame.
-fn find_in_frame<T: NativeElement>(frame: &Frame) -> Vec<(Abs, Packed<T>)> {
+pub(crate) fn find_in_frame<T: NativeElement>(frame: &Frame) -> Vec<(Abs, Packed<T>)> {
     let mut output = vec![];
     find_in_frame_impl(&mut output, frame, Abs::zero());
     output
@@ -6,7 +6,7 @@ use typst_utils::Numeric;
 
 use super::{
     Child, Composer, FlowResult, LineChild, MultiChild, MultiSpill, PlacedChild,
-    SingleChild, Stop, Work,
+    SingleChild, Stop, Work, AUTO_SPACING,
 };
 
 /// Distributes as many children as fit from `composer.work` into the first
@@ -469,6 +469,15 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
             }
         }
 
+        // Stretch automatic spacing to fill the region if requested. This isn't
+        // done where the flow ends or at explicit breaks, both of which are
+        // signalled by `forced`.
+        if let Some(limit) = self.composer.config.flush_bottom {
+            if !forced && frs.is_zero() && region.size.y.is_finite() {
+                used.y += self.stretch_spacing(region.size.y - used.y, limit);
+            }
+        }
+
         // When we have fractional spacing, occupy the remaining space with it.
         let mut fr_space = Abs::zero();
         if frs.get() > 0.0 && region.size.y.is_finite() {
@@ -552,6 +561,29 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
         Ok(output)
     }
 
+    /// Distributes up to `slack` evenly among the automatic spacing between
+    /// items, growing each gap by at most `limit`. Returns the amount of space
+    /// that was added.
+    fn stretch_spacing(&mut self, slack: Abs, limit: Abs) -> Abs {
+        let gaps = self
+            .items
+            .iter()
+            .filter(|item| matches!(item, Item::Abs(_, AUTO_SPACING)))
+            .count();
+        if gaps == 0 || slack <= Abs::zero() || limit <= Abs::zero() {
+            return Abs::zero();
+        }
+
+        let extra = (slack / gaps as f64).min(limit);
+        for item in &mut self.items {
+            if let Item::Abs(amount, AUTO_SPACING) = item {
+                *amount += extra;
+            }
+        }
+
+        extra * gaps as f64
+    }
+
     /// Create a snapshot of the work and items.
     fn snapshot(&self) -> DistributionSnapshot<'a, 'b> {
         DistributionSnapshot {
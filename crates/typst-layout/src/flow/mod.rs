@@ -6,6 +6,7 @@ mod compose;
 mod distribute;
 
 pub(crate) use self::block::unbreakable_pod;
+pub(crate) use self::compose::find_in_frame;
 
 use std::collections::HashSet;
 use std::num::NonZeroUsize;
@@ -27,12 +28,14 @@ use typst_library::layout::{
 use typst_library::model::{FootnoteElem, FootnoteEntry, LineNumberingScope, ParLine};
 use typst_library::routines::{Arenas, Pair, RealizationKind, Routines};
 use typst_library::text::TextElem;
+use typst_library::visualize::{FixedStroke, Stroke};
 use typst_library::World;
 use typst_utils::{NonZeroExt, Numeric};
 
 use self::block::{layout_multi_block, layout_single_block};
 use self::collect::{
     collect, Child, LineChild, MultiChild, MultiSpill, PlacedChild, SingleChild,
+    AUTO_SPACING,
 };
 use self::compose::{compose, Composer};
 use self::distribute::distribute;
@@ -72,6 +75,7 @@ pub fn layout_fragment(
         regions,
         NonZeroUsize::ONE,
         Rel::zero(),
+        None,
     )
 }
 
@@ -100,6 +104,7 @@ pub fn layout_columns(
         regions,
         elem.count(styles),
         elem.gutter(styles),
+        elem.separator(styles),
     )
 }
 
@@ -119,6 +124,7 @@ fn layout_fragment_impl(
     regions: Regions,
     columns: NonZeroUsize,
     column_gutter: Rel<Abs>,
+    column_separator: Option<Stroke<Abs>>,
 ) -> SourceResult<Fragment> {
     if !regions.size.x.is_finite() && regions.expand.x {
         bail!(content.span(), "cannot expand into infinite width");
@@ -158,6 +164,7 @@ fn layout_fragment_impl(
         regions,
         columns,
         column_gutter,
+        column_separator,
         false,
     )
 }
@@ -172,6 +179,7 @@ pub(crate) fn layout_flow(
     mut regions: Regions,
     columns: NonZeroUsize,
     column_gutter: Rel<Abs>,
+    column_separator: Option<Stroke<Abs>>,
     root: bool,
 ) -> SourceResult<Fragment> {
     // Prepare configuration that is shared across the whole flow.
@@ -187,7 +195,8 @@ pub(crate) fn layout_flow(
             let gutter = column_gutter.relative_to(regions.base().x);
             let width = (regions.size.x - gutter * (count - 1) as f64) / count as f64;
             let dir = TextElem::dir_in(shared);
-            ColumnConfig { count, width, gutter, dir }
+            let separator = column_separator.map(Stroke::unwrap_or_default);
+            ColumnConfig { count, width, gutter, dir, separator }
         },
         footnote: FootnoteConfig {
             separator: FootnoteEntry::separator_in(shared),
@@ -213,6 +222,7 @@ pub(crate) fn layout_flow(
                 )
             },
         }),
+        flush_bottom: if root { PageElem::flush_bottom_in(shared) } else { None },
     };
 
     // Collect the elements into pre-processed children. These are much easier
@@ -328,6 +338,9 @@ struct Config<'x> {
     footnote: FootnoteConfig,
     /// Settings for line numbers.
     line_numbers: Option<LineNumberConfig>,
+    /// By how much spacing between paragraphs and blocks may be stretched to
+    /// fill a region. Only available for the root flow.
+    flush_bottom: Option<Abs>,
 }
 
 /// Configuration of footnotes.
@@ -353,6 +366,8 @@ struct ColumnConfig {
     /// The horizontal direction in which columns progress. Defined by
     /// `text.dir`.
     dir: Dir,
+    /// How to stroke the rules between columns, if any.
+    separator: Option<FixedStroke>,
 }
 
 /// Configuration of line numbers.
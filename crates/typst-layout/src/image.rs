@@ -1,16 +1,13 @@
-use std::ffi::OsStr;
-
-use typst_library::diag::{bail, warning, At, SourceResult, StrResult};
+use typst_library::diag::{warning, At, SourceResult};
 use typst_library::engine::Engine;
 use typst_library::foundations::{Packed, Smart, StyleChain};
 use typst_library::introspection::Locator;
 use typst_library::layout::{
     Abs, Axes, FixedAlignment, Frame, FrameItem, Point, Region, Size,
 };
-use typst_library::loading::Readable;
 use typst_library::text::families;
 use typst_library::visualize::{
-    Curve, Image, ImageElem, ImageFit, ImageFormat, RasterFormat, VectorFormat,
+    Curve, Image, ImageElem, ImageFit, ImageFormat, VectorFormat,
 };
 
 /// Layout the image.
@@ -29,7 +26,7 @@ pub fn layout_image(
     let data = elem.data();
     let format = match elem.format(styles) {
         Smart::Custom(v) => v,
-        Smart::Auto => determine_format(elem.path().as_str(), data).at(span)?,
+        Smart::Auto => ImageFormat::detect(elem.path().as_str(), data).at(span)?,
     };
 
     // Warn the user if the image contains a foreign object. Not perfect
@@ -118,26 +115,3 @@ pub fn layout_image(
 
     Ok(frame)
 }
-
-/// Determine the image format based on path and data.
-fn determine_format(path: &str, data: &Readable) -> StrResult<ImageFormat> {
-    let ext = std::path::Path::new(path)
-        .extension()
-        .and_then(OsStr::to_str)
-        .unwrap_or_default()
-        .to_lowercase();
-
-    Ok(match ext.as_str() {
-        "png" => ImageFormat::Raster(RasterFormat::Png),
-        "jpg" | "jpeg" => ImageFormat::Raster(RasterFormat::Jpg),
-        "gif" => ImageFormat::Raster(RasterFormat::Gif),
-        "svg" | "svgz" => ImageFormat::Vector(VectorFormat::Svg),
-        _ => match &data {
-            Readable::Str(_) => ImageFormat::Vector(VectorFormat::Svg),
-            Readable::Bytes(bytes) => match RasterFormat::detect(bytes) {
-                Some(f) => ImageFormat::Raster(f),
-                None => bail!("unknown image format"),
-            },
-        },
-    })
-}
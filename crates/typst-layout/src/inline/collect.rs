@@ -7,7 +7,7 @@ use typst_library::layout::{
 };
 use typst_library::text::{
     is_default_ignorable, LinebreakElem, SmartQuoteElem, SmartQuoter, SmartQuotes,
-    SpaceElem, TextElem,
+    SpaceElem, TextCase, TextElem, TitleContext,
 };
 use typst_syntax::Span;
 use typst_utils::Numeric;
@@ -143,8 +143,15 @@ pub fn collect<'a>(
         collector.spans.push(1, Span::detached());
     }
 
+    // Whether the current run of title-cased text already contains a word, so
+    // that its first word is capitalized even in the middle of a paragraph.
+    let mut title_seen_word = false;
+
     for (child, styles) in children.iter(styles) {
         let prev_len = collector.full.len();
+        if TextElem::case_in(styles) != Some(TextCase::Title) {
+            title_seen_word = false;
+        }
 
         if child.is::<SpaceElem>() {
             collector.push_text(" ", styles);
@@ -161,7 +168,17 @@ pub fn collect<'a>(
                 }
 
                 if let Some(case) = TextElem::case_in(styles) {
-                    full.push_str(&case.apply(elem.text()));
+                    let context = TitleContext {
+                        in_word: full.chars().last().is_some_and(char::is_alphanumeric),
+                        seen_word: title_seen_word,
+                        skip: TextElem::case_skip_in(styles),
+                    };
+                    let cased =
+                        case.apply_in(elem.text(), TextElem::lang_in(styles), context);
+                    if case == TextCase::Title {
+                        title_seen_word |= cased.chars().any(char::is_alphanumeric);
+                    }
+                    full.push_str(&cased);
                 } else {
                     full.push_str(elem.text());
                 }
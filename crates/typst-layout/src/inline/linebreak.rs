@@ -734,12 +734,19 @@ fn hyphenations(
     word: &str,
     mut f: impl FnMut(usize, Breakpoint),
 ) {
-    let Some(lang) = lang_at(p, offset) else { return };
+    let syllables = match exception_syllables(p, offset, word) {
+        Some(syllables) => syllables,
+        None => {
+            let Some(lang) = lang_at(p, offset) else { return };
+            hypher::hyphenate(word, lang).collect()
+        }
+    };
+
     let count = word.chars().count();
     let end = offset + word.len();
 
     let mut chars = 0;
-    for syllable in hypher::hyphenate(word, lang) {
+    for syllable in syllables {
         offset += syllable.len();
         chars += syllable.chars().count();
 
@@ -840,6 +847,38 @@ fn hyphenate_at(p: &Preparation, offset: usize) -> bool {
         .unwrap_or(false)
 }
 
+/// Split a word into syllables according to a matching hyphenation exception.
+fn exception_syllables<'a>(
+    p: &Preparation,
+    offset: usize,
+    word: &'a str,
+) -> Option<Vec<&'a str>> {
+    let target = word.chars().flat_map(char::to_lowercase).collect::<String>();
+    let exception = match &p.exceptions {
+        Some(exceptions) => *exceptions.get(&target)?,
+        None => {
+            let (_, item) = p.get(offset);
+            let styles = item.text()?.styles;
+            let exceptions = TextElem::hyphenation_exceptions_in(styles);
+            *normalize_exceptions(exceptions).get(&target)?
+        }
+    };
+
+    let mut syllables = vec![];
+    let mut rest = word;
+    for piece in exception.split('-').filter(|piece| !piece.is_empty()) {
+        let len = rest
+            .char_indices()
+            .nth(piece.chars().count())
+            .map_or(rest.len(), |(i, _)| i);
+        let (syllable, tail) = rest.split_at(len);
+        syllables.push(syllable);
+        rest = tail;
+    }
+
+    Some(syllables)
+}
+
 /// The text language at the given offset.
 fn lang_at(p: &Preparation, offset: usize) -> Option<hypher::Lang> {
     let lang = p.lang.or_else(|| {
@@ -25,7 +25,7 @@ use self::deco::decorate;
 use self::finalize::finalize;
 use self::line::{commit, line, Line};
 use self::linebreak::{linebreak, Breakpoint};
-use self::prepare::{prepare, Preparation};
+use self::prepare::{normalize_exceptions, prepare, Preparation};
 use self::shaping::{
     cjk_punct_style, is_of_cj_script, shape_range, ShapedGlyph, ShapedText,
     BEGIN_PUNCT_PAT, END_PUNCT_PAT,
@@ -1,7 +1,11 @@
-use typst_library::foundations::{Resolve, Smart};
+use std::collections::HashMap;
+
+use ecow::EcoString;
+use typst_library::diag::{bail, warning};
+use typst_library::foundations::{Repr, Resolve, Smart};
 use typst_library::layout::{Abs, AlignElem, Dir, Em, FixedAlignment};
 use typst_library::model::Linebreaks;
-use typst_library::text::{Costs, Lang, TextElem};
+use typst_library::text::{Costs, Lang, MissingGlyphs, TextElem};
 use unicode_bidi::{BidiInfo, Level as BidiLevel};
 
 use super::*;
@@ -33,6 +37,9 @@ pub struct Preparation<'a> {
     pub dir: Dir,
     /// The text language if it's the same for all children.
     pub lang: Option<Lang>,
+    /// The hyphenation exceptions, keyed by their normalized word, if they
+    /// are the same for all children.
+    pub exceptions: Option<HashMap<String, &'a str>>,
     /// The paragraph's resolved horizontal alignment.
     pub align: FixedAlignment,
     /// Whether to justify the paragraph.
@@ -114,12 +121,21 @@ pub fn prepare<'a>(
         cursor = end;
     }
 
+    report_missing_glyphs(engine, &items, text, &spans)?;
+
     // Build the mapping from byte to item indices.
     let mut indices = Vec::with_capacity(text.len());
     for (i, (range, _)) in items.iter().enumerate() {
         indices.extend(range.clone().map(|_| i));
     }
 
+    let exceptions = TextElem::hyphenation_exceptions_in(styles);
+    let exceptions = items
+        .iter()
+        .filter_map(|(_, item)| item.text())
+        .all(|shaped| TextElem::hyphenation_exceptions_in(shaped.styles) == exceptions)
+        .then(|| normalize_exceptions(exceptions));
+
     let cjk_latin_spacing = TextElem::cjk_latin_spacing_in(styles).is_auto();
     if cjk_latin_spacing {
         add_cjk_latin_spacing(&mut items);
@@ -135,6 +151,7 @@ pub fn prepare<'a>(
         costs: TextElem::costs_in(styles),
         dir,
         lang: children.shared_get(styles, TextElem::lang_in),
+        exceptions,
         align: AlignElem::alignment_in(styles).resolve(styles).x,
         justify: ParElem::justify_in(styles),
         hang: ParElem::hanging_indent_in(styles),
@@ -145,6 +162,64 @@ pub fn prepare<'a>(
     })
 }
 
+/// Index hyphenation exceptions by their word, lowercased and without hyphens.
+///
+/// If multiple exceptions match the same word, the first one wins.
+pub fn normalize_exceptions(exceptions: &[EcoString]) -> HashMap<String, &str> {
+    let mut map = HashMap::with_capacity(exceptions.len());
+    for exception in exceptions {
+        let word = exception
+            .chars()
+            .filter(|&c| c != '-')
+            .flat_map(char::to_lowercase)
+            .collect();
+        map.entry(word).or_insert(exception.as_str());
+    }
+    map
+}
+
+/// Warn about or fail on text that no font has glyphs for, depending on the
+/// `missing` setting of the text.
+fn report_missing_glyphs(
+    engine: &mut Engine,
+    items: &[(Range, Item)],
+    text: &str,
+    spans: &SpanMapper,
+) -> SourceResult<()> {
+    for (_, item) in items {
+        let Item::Text(shaped) = item else { continue };
+        let mode = TextElem::missing_in(shaped.styles);
+        if mode == MissingGlyphs::Tofu {
+            continue;
+        }
+
+        // Report each run of consecutive missing glyphs once.
+        for run in shaped.glyphs.chunk_by(|a, b| (a.glyph_id == 0) == (b.glyph_id == 0)) {
+            if run[0].glyph_id != 0 {
+                continue;
+            }
+
+            let start = run.iter().map(|g| g.range.start).min().unwrap();
+            let end = run.iter().map(|g| g.range.end).max().unwrap();
+            let missing = text[start..end].repr();
+            let span = spans.span_at(start).0;
+            let hint = if TextElem::fallback_in(shaped.styles) {
+                "add a font that covers this text to the font list"
+            } else {
+                "check the font list or enable font fallback"
+            };
+            if mode == MissingGlyphs::Error {
+                bail!(span, "no font has glyphs for the text {missing}"; hint: "{hint}");
+            }
+            engine.sink.warn(warning!(
+                span, "no font has glyphs for the text {missing}";
+                hint: "{hint}"
+            ));
+        }
+    }
+    Ok(())
+}
+
 /// Add some spacing between Han characters and western characters. See
 /// Requirements for Chinese Text Layout, Section 3.2.2 Mixed Text Composition
 /// in Horizontal Written Mode
@@ -47,6 +47,10 @@ pub struct ShapedText<'a> {
     /// The width of the text's bounding box.
     pub width: Abs,
     /// The shaped glyphs.
+    ///
+    /// The glyphs are in visual order: Their text ranges are increasing for
+    /// left-to-right text and decreasing for right-to-left text. Shaping is
+    /// deterministic, so identical input always yields identical glyphs.
     pub glyphs: Cow<'a, [ShapedGlyph]>,
 }
 
@@ -9,6 +9,7 @@ mod math;
 mod pad;
 mod pages;
 mod repeat;
+mod ruby;
 mod shapes;
 mod stack;
 mod transforms;
@@ -22,6 +23,7 @@ pub use self::math::{layout_equation_block, layout_equation_inline};
 pub use self::pad::layout_pad;
 pub use self::pages::layout_document;
 pub use self::repeat::layout_repeat;
+pub use self::ruby::layout_ruby;
 pub use self::shapes::{
     layout_circle, layout_curve, layout_ellipse, layout_line, layout_path,
     layout_polygon, layout_rect, layout_square,
@@ -1,8 +1,10 @@
-use typst_library::diag::SourceResult;
+use typst_library::diag::{warning, SourceResult};
 use typst_library::engine::Engine;
 use typst_library::introspection::{ManualPageCounter, Tag};
-use typst_library::layout::{Frame, FrameItem, Page, Point};
+use typst_library::layout::{Abs, Binding, Frame, FrameItem, Page, Point};
+use typst_library::model::MarginNoteSide;
 
+use super::run::LayoutedMarginNote;
 use super::LayoutedPage;
 
 /// Piece together the inner page frame and the marginals. We can only do this
@@ -24,15 +26,20 @@ pub fn finalize(
         fill,
         numbering,
         supplement,
+        margin_notes,
     }: LayoutedPage,
 ) -> SourceResult<Page> {
     // If two sided, left becomes inside and right becomes outside.
     // Thus, for left-bound pages, we want to swap on even pages and
     // for right-bound pages, we want to swap on odd pages.
-    if two_sided && binding.swap(counter.physical()) {
+    let swapped = two_sided && binding.swap(counter.physical());
+    if swapped {
         std::mem::swap(&mut margin.left, &mut margin.right);
     }
 
+    // Whether the inside margin is the physical left one.
+    let inside_left = if two_sided { !swapped } else { binding == Binding::Left };
+
     // Create a frame for the full page.
     let mut frame = Frame::hard(inner.size() + margin.sum_by_axis());
 
@@ -52,8 +59,23 @@ pub fn finalize(
     }
 
     // Add the inner contents.
+    let right_x = margin.left + inner.width();
     frame.push_frame(Point::new(margin.left, margin.top), inner);
 
+    // Add the margin notes, stacking them per margin so that they don't
+    // overlap.
+    let (left, right): (Vec<_>, Vec<_>) =
+        margin_notes.into_iter().partition(|note| match note.side {
+            MarginNoteSide::Left => true,
+            MarginNoteSide::Right => false,
+            MarginNoteSide::Inside => inside_left,
+            MarginNoteSide::Outside => !inside_left,
+        });
+    place_margin_notes(engine, &mut frame, left, margin.top, |note| note.clearance);
+    place_margin_notes(engine, &mut frame, right, margin.top, |note| {
+        right_x + note.clearance
+    });
+
     // Add the "after" marginals.
     if let Some(footer) = footer {
         let y = frame.height() - footer.height();
@@ -72,3 +94,57 @@ pub fn finalize(
 
     Ok(Page { frame, fill, numbering, supplement, number })
 }
+
+/// Place the notes of one margin, pushing each note down as far as needed to
+/// keep it clear of the previous one.
+///
+/// Notes that would extend past the bottom of the page are pulled back up as
+/// far as the notes above them allow. If that pushes the first note past the
+/// top of the page, the notes don't fit and a warning is emitted.
+fn place_margin_notes(
+    engine: &mut Engine,
+    frame: &mut Frame,
+    mut notes: Vec<LayoutedMarginNote>,
+    top: Abs,
+    x: impl Fn(&LayoutedMarginNote) -> Abs,
+) {
+    notes.sort_by_key(|note| note.y);
+
+    let mut ys = Vec::with_capacity(notes.len());
+    let mut prev_bottom: Option<Abs> = None;
+    for note in &notes {
+        // Align the note's first baseline with the anchor's baseline.
+        let baseline =
+            if note.frame.has_baseline() { note.frame.baseline() } else { Abs::zero() };
+
+        let mut y = top + note.y - baseline;
+        if let Some(bottom) = prev_bottom {
+            y.set_max(bottom + note.gap);
+        }
+
+        prev_bottom = Some(y + note.frame.height());
+        ys.push(y);
+    }
+
+    let first = ys.first().copied();
+    let mut next_top = frame.height();
+    for (note, y) in notes.iter().zip(&mut ys).rev() {
+        y.set_min(next_top - note.frame.height());
+        next_top = *y - note.gap;
+    }
+
+    // Pulling the notes up may have moved the first one off the page.
+    if let Some(((note, &y), first)) = notes.first().zip(ys.first()).zip(first) {
+        if y < first && y < Abs::zero() {
+            engine.sink.warn(warning!(
+                note.span,
+                "margin notes do not fit into the margin";
+                hint: "try shortening the notes or moving some of them to another page",
+            ));
+        }
+    }
+
+    for (note, y) in notes.into_iter().zip(ys) {
+        frame.push_frame(Point::new(x(&note), y), note.frame);
+    }
+}
@@ -12,14 +12,15 @@ use typst_library::layout::{
     Length, OuterVAlignment, PageElem, Paper, Region, Regions, Rel, Sides, Size,
     VAlignment,
 };
-use typst_library::model::Numbering;
+use typst_library::model::{MarginNoteElem, MarginNoteSide, Numbering};
 use typst_library::routines::{Pair, Routines};
 use typst_library::text::{LocalName, TextElem};
 use typst_library::visualize::Paint;
 use typst_library::World;
+use typst_syntax::Span;
 use typst_utils::Numeric;
 
-use crate::flow::layout_flow;
+use crate::flow::{find_in_frame, layout_flow};
 
 /// A mostly finished layout for one page. Needs only knowledge of its exact
 /// page number to be finalized into a `Page`. (Because the margins can depend
@@ -37,6 +38,24 @@ pub struct LayoutedPage {
     pub fill: Smart<Option<Paint>>,
     pub numbering: Option<Numbering>,
     pub supplement: Content,
+    pub margin_notes: Vec<LayoutedMarginNote>,
+}
+
+/// A laid-out margin note that still needs to be placed into its margin.
+#[derive(Clone)]
+pub struct LayoutedMarginNote {
+    /// The vertical position of the anchor in the inner frame.
+    pub y: Abs,
+    /// The margin into which the note goes.
+    pub side: MarginNoteSide,
+    /// The horizontal space to the text area and the page edge.
+    pub clearance: Abs,
+    /// The minimum vertical space to the previous note.
+    pub gap: Abs,
+    /// The note's contents.
+    pub frame: Frame,
+    /// The span of the note.
+    pub span: Span,
 }
 
 /// Layout a single page suitable  for parity adjustment.
@@ -181,9 +200,37 @@ fn layout_page_run_impl(
         Regions::repeat(area, area.map(Abs::is_finite)),
         PageElem::columns_in(styles),
         ColumnsElem::gutter_in(styles),
+        ColumnsElem::separator_in(styles),
         true,
     )?;
 
+    // Layout the margin notes, which we can't yet place because the final
+    // side of inside and outside notes depends on the physical page number.
+    let mut margin_notes = Vec::with_capacity(fragment.len());
+    for inner in &fragment {
+        let mut notes = vec![];
+        for (y, elem) in find_in_frame::<MarginNoteElem>(inner) {
+            // Lay out the note with the styles active where it appears.
+            let local = elem.styles().cloned().unwrap_or_default();
+            let styles = styles.chain(&local);
+            let side = elem.side(styles);
+            let clearance = elem.clearance(styles).resolve(styles);
+            let width = margin_note_width(side, margin, binding, two_sided);
+            let width = (width - 2.0 * clearance).max(Abs::zero());
+            let frame = crate::layout_frame(
+                &mut engine,
+                &elem.body,
+                locator.next(&elem.span()),
+                styles,
+                Region::new(Size::new(width, Abs::inf()), Axes::new(true, false)),
+            )?;
+            let gap = elem.gap(styles).resolve(styles);
+            let span = elem.span();
+            notes.push(LayoutedMarginNote { y, side, clearance, gap, frame, span });
+        }
+        margin_notes.push(notes);
+    }
+
     // Layouts a single marginal.
     let mut layout_marginal = |content: &Option<Content>, area, align| {
         let Some(content) = content else { return Ok(None) };
@@ -200,7 +247,7 @@ fn layout_page_run_impl(
 
     // Layout marginals.
     let mut layouted = Vec::with_capacity(fragment.len());
-    for inner in fragment {
+    for (inner, margin_notes) in fragment.into_iter().zip(margin_notes) {
         let header_size = Size::new(inner.width(), margin.top - header_ascent);
         let footer_size = Size::new(inner.width(), margin.bottom - footer_descent);
         let full_size = inner.size() + margin.sum_by_axis();
@@ -217,12 +264,41 @@ fn layout_page_run_impl(
             margin,
             binding,
             two_sided,
+            margin_notes,
         });
     }
 
     Ok(layouted)
 }
 
+/// Determines the width of the margin into which a note on the given side goes.
+///
+/// Before finalization, the left margin is the inside one and the right
+/// margin is the outside one on two-sided pages. Since we don't know yet which
+/// of them ends up on which physical side, notes that want a physical side get
+/// the narrower of the two.
+fn margin_note_width(
+    side: MarginNoteSide,
+    margin: Sides<Abs>,
+    binding: Binding,
+    two_sided: bool,
+) -> Abs {
+    let (inside, outside) = if two_sided || binding == Binding::Left {
+        (margin.left, margin.right)
+    } else {
+        (margin.right, margin.left)
+    };
+
+    match side {
+        MarginNoteSide::Inside => inside,
+        MarginNoteSide::Outside => outside,
+        MarginNoteSide::Left if two_sided => margin.left.min(margin.right),
+        MarginNoteSide::Right if two_sided => margin.left.min(margin.right),
+        MarginNoteSide::Left => margin.left,
+        MarginNoteSide::Right => margin.right,
+    }
+}
+
 /// Determines the styles used for a page run itself and page-level content like
 /// marginals and footnotes.
 ///
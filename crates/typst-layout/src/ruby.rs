@@ -0,0 +1,114 @@
+use typst_library::diag::{bail, warning, SourceResult};
+use typst_library::engine::Engine;
+use typst_library::foundations::{Content, Packed, StyleChain};
+use typst_library::introspection::{Locator, SplitLocator};
+use typst_library::layout::{Abs, Axes, Frame, InlineItem, Point, Region, Size};
+use typst_library::text::{RubyElem, RubyMode, SpaceElem, TextElem};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Layout a ruby annotation into inline items.
+#[typst_macros::time(span = elem.span())]
+pub fn layout_ruby(
+    elem: &Packed<RubyElem>,
+    engine: &mut Engine,
+    locator: Locator,
+    styles: StyleChain,
+    _: Size,
+) -> SourceResult<Vec<InlineItem>> {
+    let mut locator = locator.split();
+    let annotation_styles = TextElem::set_size(elem.size(styles)).wrap();
+    let annotation_styles = styles.chain(&annotation_styles);
+    let gap = elem.gap(styles);
+
+    let mut layout = |base: &Content, annotation: &Content| {
+        layout_group(
+            engine,
+            &mut locator,
+            base,
+            annotation,
+            styles,
+            annotation_styles,
+            gap,
+        )
+    };
+
+    let groups = match elem.mode(styles) {
+        RubyMode::Group => vec![layout(&elem.base, &elem.annotation)?],
+        RubyMode::Mono => {
+            // Styling within the base and annotation is lost here, and
+            // whitespace in the base does not receive an annotation part.
+            if !is_plain(&elem.base) || !is_plain(&elem.annotation) {
+                engine.sink.warn(warning!(
+                    elem.span(),
+                    "styling within a mono ruby is ignored";
+                    hint: "apply the styling to the whole ruby instead",
+                ));
+            }
+
+            let base = elem.base.plain_text();
+            let annotation = elem.annotation.plain_text();
+            let bases: Vec<_> = base
+                .graphemes(true)
+                .filter(|grapheme| !grapheme.trim().is_empty())
+                .collect();
+            let parts: Vec<_> = annotation.split_whitespace().collect();
+            if bases.len() != parts.len() {
+                bail!(
+                    elem.span(),
+                    "expected {} annotation parts, found {}",
+                    bases.len(),
+                    parts.len();
+                    hint: "separate the annotations of the characters with spaces",
+                );
+            }
+
+            bases
+                .into_iter()
+                .zip(parts)
+                .map(|(base, part)| {
+                    layout(&TextElem::packed(base), &TextElem::packed(part))
+                })
+                .collect::<SourceResult<_>>()?
+        }
+    };
+
+    Ok(groups.into_iter().map(InlineItem::Frame).collect())
+}
+
+/// Whether the content consists of nothing but unstyled text and spaces.
+fn is_plain(content: &Content) -> bool {
+    let mut plain = true;
+    content.sequence_recursive_for_each(&mut |child| {
+        plain &= child.is::<TextElem>() || child.is::<SpaceElem>();
+    });
+    plain
+}
+
+/// Layout a single base text with its annotation centered above it.
+fn layout_group(
+    engine: &mut Engine,
+    locator: &mut SplitLocator,
+    base: &Content,
+    annotation: &Content,
+    styles: StyleChain,
+    annotation_styles: StyleChain,
+    gap: Abs,
+) -> SourceResult<Frame> {
+    let pod = Region::new(Axes::splat(Abs::inf()), Axes::splat(false));
+    let base = crate::layout_frame(engine, base, locator.next(&()), styles, pod)?;
+    let annotation = crate::layout_frame(
+        engine,
+        annotation,
+        locator.next(&()),
+        annotation_styles,
+        pod,
+    )?;
+
+    let width = base.width().max(annotation.width());
+    let base_y = annotation.height() + gap;
+    let mut frame = Frame::soft(Size::new(width, base_y + base.height()));
+    frame.set_baseline(base_y + base.baseline());
+    frame.push_frame(Point::with_x((width - annotation.width()) / 2.0), annotation);
+    frame.push_frame(Point::new((width - base.width()) / 2.0, base_y), base);
+    Ok(frame)
+}
@@ -92,8 +92,7 @@ pub fn layout_path(
             kurbo::Point::new(to_control_point.x.to_raw(), to_control_point.y.to_raw());
         let p3 = kurbo::Point::new(to_point.x.to_raw(), to_point.y.to_raw());
         let extrema = kurbo::CubicBez::new(p0, p1, p2, p3).bounding_box();
-        size.x.set_max(Abs::raw(extrema.x1));
-        size.y.set_max(Abs::raw(extrema.y1));
+        size = size.max(Size::new(Abs::raw(extrema.x1), Abs::raw(extrema.y1)));
     };
 
     for (vertex_window, point_window) in vertices.windows(2).zip(points.windows(2)) {
@@ -318,8 +317,7 @@ impl<'a> CurveBuilder<'a> {
         let p2 = point_to_kurbo(c2);
         let p3 = point_to_kurbo(end);
         let extrema = CubicBez::new(p0, p1, p2, p3).bounding_box();
-        self.size.x.set_max(Abs::raw(extrema.x1));
-        self.size.y.set_max(Abs::raw(extrema.y1));
+        self.size = self.size.max(Size::new(Abs::raw(extrema.x1), Abs::raw(extrema.y1)));
 
         self.last_point = end;
         self.last_control_from = mirror_c(end, c2);
@@ -352,8 +350,7 @@ impl<'a> CurveBuilder<'a> {
 
     /// Expand the curve's bounding box.
     fn expand_bounds(&mut self, point: Point) {
-        self.size.x.set_max(point.x);
-        self.size.y.set_max(point.y);
+        self.size = self.size.max(point.to_size());
     }
 
     /// Resolve the point relative to the region.
@@ -464,6 +461,7 @@ pub fn layout_rect(
         elem.inset(styles),
         elem.outset(styles),
         elem.radius(styles),
+        elem.clip(styles),
         elem.span(),
     )
 }
@@ -489,6 +487,7 @@ pub fn layout_square(
         elem.inset(styles),
         elem.outset(styles),
         elem.radius(styles),
+        elem.clip(styles),
         elem.span(),
     )
 }
@@ -514,6 +513,7 @@ pub fn layout_ellipse(
         elem.inset(styles),
         elem.outset(styles),
         Corners::splat(None),
+        elem.clip(styles),
         elem.span(),
     )
 }
@@ -539,6 +539,7 @@ pub fn layout_circle(
         elem.inset(styles),
         elem.outset(styles),
         Corners::splat(None),
+        elem.clip(styles),
         elem.span(),
     )
 }
@@ -582,13 +583,15 @@ fn layout_shape(
     inset: Sides<Option<Rel<Abs>>>,
     outset: Sides<Option<Rel<Abs>>>,
     radius: Corners<Option<Rel<Abs>>>,
+    clip: bool,
     span: Span,
 ) -> SourceResult<Frame> {
     let mut frame;
     if let Some(child) = body {
         let mut inset = inset.unwrap_or_default();
-        if kind.is_round() {
-            // Apply extra inset to round shapes.
+        if kind.is_round() && !clip {
+            // Apply extra inset to round shapes. When clipping, the content
+            // instead fills the whole bounding box and is cut to the curve.
             inset = inset.map(|v| v + Ratio::new(0.5 - SQRT_2 / 4.0));
         }
         let has_inset = !inset.is_zero();
@@ -653,6 +656,21 @@ fn layout_shape(
         }
     };
 
+    // Clip the contents, if requested.
+    if clip {
+        let outset = outset.unwrap_or_default();
+        if kind.is_round() {
+            frame.clip(clip_ellipse(frame.size(), stroke.left.as_ref(), &outset));
+        } else {
+            frame.clip(clip_rect(
+                frame.size(),
+                &radius.unwrap_or_default(),
+                &stroke,
+                &outset,
+            ));
+        }
+    }
+
     // Add fill and/or stroke.
     if fill.is_some() || stroke.iter().any(Option::is_some) {
         if kind.is_round() {
@@ -745,6 +763,20 @@ pub fn clip_rect(
     curve
 }
 
+/// Creates a new ellipse as a curve, within the inner edge of the stroke.
+fn clip_ellipse(
+    size: Size,
+    stroke: Option<&FixedStroke>,
+    outset: &Sides<Rel<Abs>>,
+) -> Curve {
+    let outset = outset.relative_to(size);
+    let inner = stroke.map_or(Abs::zero(), |s| s.thickness / 2.0);
+    let size = (size + outset.sum_by_axis() - Size::splat(2.0 * inner)).max(Size::zero());
+    let mut curve = Curve::ellipse(size);
+    curve.translate(Point::new(inner - outset.left, inner - outset.top));
+    curve
+}
+
 /// Add a fill and stroke with optional radius and outset to the frame.
 pub fn fill_and_stroke(
     frame: &mut Frame,
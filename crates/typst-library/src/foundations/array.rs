@@ -701,13 +701,26 @@ impl Array {
     }
 
     /// Combine all items in the array into one.
+    ///
+    /// Joining strings results in a string and joining content results in
+    /// content. An empty array yields `{none}` and an array with a single
+    /// item yields just that item.
+    ///
+    /// ```example
+    /// #("a", "b", "c").join(", ") \
+    /// #([Apples], [Pears], [Plums]).join(
+    ///   [, ],
+    ///   last: [ and ],
+    /// )
+    /// ```
     #[func]
     pub fn join(
         self,
         /// A value to insert between each item of the array.
         #[default]
         separator: Option<Value>,
-        /// An alternative separator between the last two items.
+        /// An alternative separator between the last two items. This is
+        /// useful for natural-language lists like "a, b and c".
         #[named]
         last: Option<Value>,
     ) -> StrResult<Value> {
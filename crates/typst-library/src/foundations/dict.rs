@@ -46,7 +46,8 @@ pub use crate::__dict as dict;
 ///
 /// You can iterate over the pairs in a dictionary using a [for
 /// loop]($scripting/#loops). This will iterate in the order the pairs were
-/// inserted / declared.
+/// inserted / declared. This order does not matter for comparisons though: Two
+/// dictionaries are equal if they contain the same pairs.
 ///
 /// # Example
 /// ```example
@@ -317,10 +318,15 @@ impl AddAssign for Dict {
 
 impl Hash for Dict {
     fn hash<H: Hasher>(&self, state: &mut H) {
+        // Dictionaries compare equal regardless of the order of their pairs,
+        // so their hash must not depend on it either. We thus combine the
+        // hashes of the individual pairs with a commutative operation.
         state.write_usize(self.0.len());
-        for item in self {
-            item.hash(state);
-        }
+        state.write_u128(
+            self.iter()
+                .map(|pair| typst_utils::hash128(&pair))
+                .fold(0, u128::wrapping_add),
+        );
     }
 }
 
@@ -1,5 +1,3 @@
-use std::num::ParseFloatError;
-
 use ecow::{eco_format, EcoString};
 
 use crate::diag::{bail, StrResult};
@@ -45,7 +43,9 @@ impl f64 {
     ///   exact.
     /// - Ratios are divided by 100%.
     /// - Strings are parsed in base 10 to the closest 64-bit float. Exponential
-    ///   notation is supported.
+    ///   notation and a leading sign are supported, and surrounding whitespace
+    ///   is ignored. Numbers that are too large to be represented produce an
+    ///   error.
     ///
     /// ```example
     /// #float(false) \
@@ -196,12 +196,22 @@ cast! {
     v: i64 => Self(v as f64),
     v: Decimal => Self(f64::try_from(v).map_err(|_| eco_format!("invalid float: {}", v))?),
     v: Ratio => Self(v.get()),
-    v: Str => Self(
-        parse_float(v.clone().into())
-            .map_err(|_| eco_format!("invalid float: {}", v))?
-    ),
+    v: Str => Self(parse_float(&v)?),
 }
 
-fn parse_float(s: EcoString) -> Result<f64, ParseFloatError> {
-    s.replace(repr::MINUS_SIGN, "-").parse()
+/// Parses a float from a string, allowing surrounding whitespace.
+fn parse_float(s: &str) -> StrResult<f64> {
+    let trimmed = s.trim();
+    let float: f64 = trimmed
+        .replace(repr::MINUS_SIGN, "-")
+        .parse()
+        .map_err(|_| eco_format!("invalid float: {}", s))?;
+
+    // Rust parses out-of-range numbers to infinity, but that is only expected
+    // if infinity was spelled out.
+    if float.is_infinite() && !trimmed.to_lowercase().contains("inf") {
+        bail!("number too large");
+    }
+
+    Ok(float)
 }
@@ -1,8 +1,9 @@
-use std::num::{NonZeroI64, NonZeroIsize, NonZeroU64, NonZeroUsize, ParseIntError};
+use std::num::{IntErrorKind, NonZeroI64, NonZeroIsize, NonZeroU64, NonZeroUsize};
 
 use ecow::{eco_format, EcoString};
+use typst_syntax::{Span, Spanned};
 
-use crate::diag::{bail, StrResult};
+use crate::diag::{bail, At, SourceResult, StrResult};
 use crate::foundations::{
     cast, func, repr, scope, ty, Bytes, Cast, Decimal, Repr, Str, Value,
 };
@@ -44,21 +45,42 @@ impl i64 {
     ///
     /// - Booleans are converted to `0` or `1`.
     /// - Floats and decimals are truncated to the next 64-bit integer.
-    /// - Strings are parsed in base 10.
+    /// - Strings are parsed in base 10, or in the given `base`. A leading sign
+    ///   is allowed and surrounding whitespace is ignored.
     ///
     /// ```example
     /// #int(false) \
     /// #int(true) \
     /// #int(2.7) \
     /// #int(decimal("3.8")) \
-    /// #(int("27") + int("4"))
+    /// #(int("27") + int("4")) \
+    /// #int("0x1F", base: 16)
     /// ```
     #[func(constructor)]
     pub fn construct(
         /// The value that should be converted to an integer.
-        value: ToInt,
-    ) -> i64 {
-        value.0
+        value: Spanned<ToInt>,
+        /// The base (radix) to parse strings in, between 2 and 36. For bases
+        /// 16, 8, and 2, the string may start with the matching prefix `0x`,
+        /// `0o`, or `0b`.
+        #[named]
+        #[default(Spanned::new(10, Span::detached()))]
+        base: Spanned<i64>,
+    ) -> SourceResult<i64> {
+        Ok(match value.v {
+            ToInt::Int(n) => {
+                if base.v != 10 {
+                    bail!(base.span, "base is only supported for strings");
+                }
+                n
+            }
+            ToInt::Str(s) => {
+                if base.v < 2 || base.v > 36 {
+                    bail!(base.span, "base must be between 2 and 36");
+                }
+                parse_int(&s, base.v as u32).at(value.span)?
+            }
+        })
     }
 
     /// Calculates the sign of an integer.
@@ -362,15 +384,20 @@ pub enum Endianness {
 }
 
 /// A value that can be cast to an integer.
-pub struct ToInt(i64);
+pub enum ToInt {
+    /// An already converted integer.
+    Int(i64),
+    /// A string that still needs to be parsed.
+    Str(Str),
+}
 
 cast! {
     ToInt,
-    v: i64 => Self(v),
-    v: bool => Self(v as i64),
-    v: f64 => Self(convert_float_to_int(v)?),
-    v: Decimal => Self(i64::try_from(v).map_err(|_| eco_format!("number too large"))?),
-    v: Str => Self(parse_int(&v).map_err(|_| eco_format!("invalid integer: {}", v))?),
+    v: i64 => Self::Int(v),
+    v: bool => Self::Int(v as i64),
+    v: f64 => Self::Int(convert_float_to_int(v)?),
+    v: Decimal => Self::Int(i64::try_from(v).map_err(|_| eco_format!("number too large"))?),
+    v: Str => Self::Str(v),
 }
 
 pub fn convert_float_to_int(f: f64) -> StrResult<i64> {
@@ -381,16 +408,55 @@ pub fn convert_float_to_int(f: f64) -> StrResult<i64> {
     }
 }
 
-fn parse_int(mut s: &str) -> Result<i64, ParseIntError> {
-    let mut sign = 1;
-    if let Some(rest) = s.strip_prefix('-').or_else(|| s.strip_prefix(repr::MINUS_SIGN)) {
-        sign = -1;
-        s = rest;
+/// Parses an integer in the given base, allowing a sign, a base prefix, and
+/// surrounding whitespace.
+fn parse_int(s: &str, base: u32) -> StrResult<i64> {
+    let invalid = || eco_format!("invalid integer: {}", s);
+
+    let mut digits = s.trim();
+    let mut negative = false;
+    if let Some(rest) = digits
+        .strip_prefix('-')
+        .or_else(|| digits.strip_prefix(repr::MINUS_SIGN))
+    {
+        negative = true;
+        digits = rest;
+    } else if let Some(rest) = digits.strip_prefix('+') {
+        digits = rest;
     }
-    if sign == -1 && s == "9223372036854775808" {
-        return Ok(i64::MIN);
+
+    let prefix = match base {
+        16 => Some("0x"),
+        8 => Some("0o"),
+        2 => Some("0b"),
+        _ => None,
+    };
+    if let Some(rest) = prefix.and_then(|p| {
+        digits
+            .get(..2)
+            .filter(|d| d.eq_ignore_ascii_case(p))
+            .map(|_| &digits[2..])
+    }) {
+        digits = rest;
+    }
+
+    // `from_str_radix` accepts a sign itself, which we already handled.
+    if digits.starts_with(['+', '-']) {
+        return Err(invalid());
+    }
+
+    let magnitude =
+        u64::from_str_radix(digits, base).map_err(|err| match err.kind() {
+            IntErrorKind::PosOverflow => eco_format!("number too large"),
+            _ => invalid(),
+        })?;
+
+    if negative {
+        0i64.checked_sub_unsigned(magnitude)
+    } else {
+        i64::try_from(magnitude).ok()
     }
-    Ok(sign * s.parse::<i64>()?)
+    .ok_or_else(|| eco_format!("number too large"))
 }
 
 macro_rules! signed_int {
@@ -167,6 +167,18 @@ pub fn panic(
 /// ```typ
 /// #assert(1 < 2, message: "math broke")
 /// ```
+///
+/// # Validating inputs
+/// Assertions are a good way for templates and other reusable functions to
+/// check their arguments. The resulting error is reported like any other error
+/// in the document, at the failing assertion.
+///
+/// ```typ
+/// #let grid-of(columns, ..items) = {
+///   assert(columns > 0, message: "columns must be positive")
+///   grid(columns: columns, ..items)
+/// }
+/// ```
 #[func(scope)]
 pub fn assert(
     /// The condition that must be true for the assertion to pass.
@@ -258,6 +270,14 @@ impl assert {
 ///
 /// This function should only be used as a last resort.
 ///
+/// The string is evaluated in a fresh scope: It can access the standard
+/// library and the definitions passed via `scope`, but not the variables of
+/// the surrounding code. Like any Typst code, it can read files in the
+/// project with [`read`] or [`image`], but it cannot access anything outside
+/// of it. Errors in the evaluated string are reported at the string argument.
+/// Nested evaluations count towards the maximum function call depth, so an
+/// evaluated string that recursively evaluates itself fails with an error.
+///
 /// # Example
 /// ```example
 /// #eval("1 + 1") \
@@ -303,5 +323,13 @@ pub fn eval(
     for (key, value) in dict {
         scope.define_spanned(key, value, span);
     }
-    (engine.routines.eval_string)(engine.routines, engine.world, &text, span, mode, scope)
+    (engine.routines.eval_string)(
+        engine.routines,
+        engine.world,
+        engine.route.track(),
+        &text,
+        span,
+        mode,
+        scope,
+    )
 }
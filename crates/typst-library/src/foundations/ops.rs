@@ -9,7 +9,7 @@ use crate::diag::{bail, HintedStrResult, StrResult};
 use crate::foundations::{format_str, Datetime, IntoValue, Regex, Repr, Value};
 use crate::layout::{Alignment, Length, Rel};
 use crate::text::TextElem;
-use crate::visualize::Stroke;
+use crate::visualize::{Spot, Stroke};
 
 /// Bail with a type mismatch error.
 macro_rules! mismatch {
@@ -149,6 +149,12 @@ pub fn add(lhs: Value, rhs: Value) -> HintedStrResult<Value> {
         (Tiling(tiling), Length(thickness)) | (Length(thickness), Tiling(tiling)) => {
             Stroke::from_pair(tiling, thickness).into_value()
         }
+        (Dyn(spot), Length(thickness)) | (Length(thickness), Dyn(spot))
+            if spot.is::<Spot>() =>
+        {
+            let spot = spot.downcast::<Spot>().unwrap().clone();
+            Stroke::from_pair(spot, thickness).into_value()
+        }
 
         (Duration(a), Duration(b)) => Duration(a + b),
         (Datetime(a), Duration(b)) => Datetime(a + b),
@@ -195,12 +201,12 @@ pub fn sub(lhs: Value, rhs: Value) -> HintedStrResult<Value> {
         (Length(a), Ratio(b)) => Relative(-b + a),
         (Length(a), Relative(b)) => Relative(-b + a),
 
-        (Ratio(a), Length(b)) => Relative(a + -b),
+        (Ratio(a), Length(b)) => Relative(a - b),
         (Ratio(a), Ratio(b)) => Ratio(a - b),
         (Ratio(a), Relative(b)) => Relative(-b + a),
 
-        (Relative(a), Length(b)) => Relative(a + -b),
-        (Relative(a), Ratio(b)) => Relative(a + -b),
+        (Relative(a), Length(b)) => Relative(a - b),
+        (Relative(a), Ratio(b)) => Relative(a - b),
         (Relative(a), Relative(b)) => Relative(a - b),
 
         (Fraction(a), Fraction(b)) => Fraction(a - b),
@@ -129,6 +129,12 @@ pub fn display_float(value: f64) -> EcoString {
     }
 }
 
+/// Converts a float to a string that can be used to display the float as text,
+/// with a precision of two decimal places and followed by a unit.
+pub fn display_float_with_unit(value: f64, unit: &str) -> EcoString {
+    eco_format!("{}{unit}", display_float(round_with_precision(value, 2)))
+}
+
 /// Formats pieces separated with commas and a final "and" or "or".
 pub fn separated_list(pieces: &[impl AsRef<str>], last: &str) -> String {
     let mut buf = String::new();
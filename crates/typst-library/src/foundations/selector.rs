@@ -11,6 +11,8 @@ use crate::foundations::{
     Func, Label, Reflect, Regex, Repr, Str, StyleChain, Symbol, Type, Value,
 };
 use crate::introspection::{Introspector, Locatable, Location, Unqueriable};
+use crate::model::TableElem;
+use crate::visualize::ImageElem;
 
 /// A helper macro to create a field selector used in [`Selector::Elem`]
 #[macro_export]
@@ -335,10 +337,20 @@ cast! {
 
 impl FromValue for LocatableSelector {
     fn from_value(value: Value) -> HintedStrResult<Self> {
-        fn validate(selector: &Selector) -> StrResult<()> {
+        fn validate(selector: &Selector) -> HintedStrResult<()> {
             match selector {
                 Selector::Elem(elem, _) => {
                     if !elem.can::<dyn Locatable>() || elem.can::<dyn Unqueriable>() {
+                        if *elem == Element::of::<TableElem>()
+                            || *elem == Element::of::<ImageElem>()
+                        {
+                            bail!(
+                                "{} is not locatable", elem.name();
+                                hint: "try to select the figures containing them instead: \
+                                       `figure.where(kind: {})`",
+                                elem.name(),
+                            );
+                        }
                         Err(eco_format!("{} is not locatable", elem.name()))?
                     }
                 }
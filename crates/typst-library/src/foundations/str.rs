@@ -4,18 +4,20 @@ use std::hash::{Hash, Hasher};
 use std::ops::{Add, AddAssign, Deref, Range};
 
 use comemo::Tracked;
-use ecow::EcoString;
+use ecow::{eco_format, EcoString};
 use serde::{Deserialize, Serialize};
 use typst_syntax::{Span, Spanned};
+use typst_utils::Numeric;
+use unicode_normalization::UnicodeNormalization;
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::diag::{bail, At, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, dict, func, repr, scope, ty, Array, Bytes, Context, Decimal, Dict, Func,
+    cast, dict, func, repr, scope, ty, Array, Bytes, Cast, Context, Decimal, Dict, Func,
     IntoValue, Label, Repr, Type, Value, Version,
 };
-use crate::layout::Alignment;
+use crate::layout::{Alignment, Angle, Fr, Length, Ratio, Rel};
 
 /// Create a new [`Str`] from a format string.
 #[macro_export]
@@ -132,9 +134,19 @@ impl Str {
     /// - Floats are formatted in base 10 and never in exponential notation.
     /// - Negative integers and floats are formatted with the Unicode minus sign
     ///   ("−" U+2212) instead of the ASCII minus sign ("-" U+002D).
+    /// - Booleans become `{"true"}` or `{"false"}`.
+    /// - Lengths, angles, ratios, relative lengths, and fractions are
+    ///   formatted with their unit, like `{"1.5pt"}`, `{"50%"}`, or
+    ///   `{"50% + 1pt"}`. Absolute lengths are always given in points.
     /// - From labels the name is extracted.
     /// - Bytes are decoded as UTF-8.
     ///
+    /// Other values, like functions or content, cannot be converted and
+    /// produce an error. Unlike [`repr`], which produces a debug representation
+    /// of any value (e.g. with quotes around strings), this function is meant
+    /// for user-facing output: `{str("hi")}` is `{"hi"}`, while `{repr("hi")}`
+    /// is `{"\"hi\""}`.
+    ///
     /// If you wish to convert from and to Unicode code points, see the
     /// [`to-unicode`]($str.to-unicode) and [`from-unicode`]($str.from-unicode)
     /// functions.
@@ -144,6 +156,8 @@ impl Str {
     /// #str(4000, base: 16) \
     /// #str(2.7) \
     /// #str(1e8) \
+    /// #str(true) \
+    /// #str(1.5pt) \
     /// #str(<intro>)
     /// ```
     #[func(constructor)]
@@ -600,6 +614,32 @@ impl Str {
         }
     }
 
+    /// Normalizes the string to the given Unicode normal form.
+    ///
+    /// This is useful when manipulating strings containing Unicode combining
+    /// characters, as the same text can be encoded in different ways.
+    ///
+    /// ```example
+    /// #let decomposed = "e\u{301}"
+    /// #(decomposed == "\u{e9}") \
+    /// #(decomposed.normalize() == "\u{e9}")
+    /// ```
+    #[func]
+    pub fn normalize(
+        &self,
+        /// The Unicode normal form to normalize to.
+        #[named]
+        #[default(UnicodeNormalForm::Nfc)]
+        form: UnicodeNormalForm,
+    ) -> Str {
+        match form {
+            UnicodeNormalForm::Nfc => self.nfc().collect(),
+            UnicodeNormalForm::Nfd => self.nfd().collect(),
+            UnicodeNormalForm::Nfkc => self.nfkc().collect(),
+            UnicodeNormalForm::Nfkd => self.nfkd().collect(),
+        }
+    }
+
     /// Reverse the string.
     #[func(title = "Reverse")]
     pub fn rev(&self) -> Str {
@@ -783,6 +823,12 @@ cast! {
     v: i64 => Self::Int(v),
     v: f64 => Self::Str(repr::display_float(v).into()),
     v: Decimal => Self::Str(format_str!("{}", v)),
+    v: bool => Self::Str(if v { "true" } else { "false" }.into()),
+    v: Length => Self::Str(display_length(v).into()),
+    v: Angle => Self::Str(repr::display_float_with_unit(v.to_deg(), "deg").into()),
+    v: Ratio => Self::Str(display_ratio(v).into()),
+    v: Rel<Length> => Self::Str(display_relative(v).into()),
+    v: Fr => Self::Str(repr::display_float_with_unit(v.get(), "fr").into()),
     v: Version => Self::Str(format_str!("{}", v)),
     v: Bytes => Self::Str(
         std::str::from_utf8(&v)
@@ -794,6 +840,33 @@ cast! {
     v: Str => Self::Str(v),
 }
 
+/// Formats a length for display, with absolute lengths in points.
+fn display_length(length: Length) -> EcoString {
+    let abs = || repr::display_float_with_unit(length.abs.to_pt(), "pt");
+    let em = || repr::display_float_with_unit(length.em.get(), "em");
+    match (length.abs.is_zero(), length.em.is_zero()) {
+        (false, false) => eco_format!("{} + {}", abs(), em()),
+        (true, false) => em(),
+        (_, true) => abs(),
+    }
+}
+
+/// Formats a ratio for display as a percentage.
+fn display_ratio(ratio: Ratio) -> EcoString {
+    repr::display_float_with_unit(ratio.get() * 100.0, "%")
+}
+
+/// Formats a relative length for display.
+fn display_relative(rel: Rel<Length>) -> EcoString {
+    match (rel.rel.is_zero(), rel.abs.is_zero()) {
+        (false, false) => {
+            eco_format!("{} + {}", display_ratio(rel.rel), display_length(rel.abs))
+        }
+        (false, true) => display_ratio(rel.rel),
+        (true, _) => display_length(rel.abs),
+    }
+}
+
 /// Convert an item of std's `match_indices` to a dictionary.
 fn match_to_dict((start, text): (usize, &str)) -> Dict {
     dict! {
@@ -937,6 +1010,21 @@ cast! {
     v: Regex => Self::Regex(v),
 }
 
+/// A Unicode normalization form.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum UnicodeNormalForm {
+    /// Canonical composition where e.g. accented letters are turned into a
+    /// single Unicode codepoint.
+    Nfc,
+    /// Canonical decomposition where e.g. accented letters are split into a
+    /// separate base and diacritic.
+    Nfd,
+    /// Like NFC, but using the Unicode compatibility decompositions.
+    Nfkc,
+    /// Like NFD, but using the Unicode compatibility decompositions.
+    Nfkd,
+}
+
 /// A side of a string.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub enum StrSide {
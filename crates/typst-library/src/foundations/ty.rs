@@ -44,6 +44,26 @@ use crate::foundations::{
 /// #type(int) \
 /// #type(type)
 /// ```
+///
+/// # Checking types
+/// To validate the arguments of a function, compare a value's type with the
+/// type itself rather than with its name. This also catches typos, since a
+/// misspelled type is an unknown variable. If you need the name as a string,
+/// for example for an error message, convert the type with [`str`]. To inspect
+/// a value for debugging, use [`repr`].
+///
+/// ```example
+/// #let describe(x) = {
+///   assert(
+///     type(x) in (int, float),
+///     message: "expected number, found " + str(type(x)),
+///   )
+///   repr(x) + " is a " + str(type(x))
+/// }
+///
+/// #describe(1) \
+/// #describe(2.5)
+/// ```
 #[ty(scope, cast)]
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Type(Static<NativeTypeData>);
@@ -758,4 +758,39 @@ mod tests {
         test(dict!["one" => 1], "(one: 1)");
         test(dict!["two" => false, "one" => 1], "(two: false, one: 1)");
     }
+
+    #[track_caller]
+    fn test_hash_eq(a: impl IntoValue, b: impl IntoValue, eq: bool) {
+        let (a, b) = (a.into_value(), b.into_value());
+        assert_eq!(a == b, eq);
+        if eq {
+            assert_eq!(typst_utils::hash128(&a), typst_utils::hash128(&b));
+        } else {
+            assert_ne!(typst_utils::hash128(&a), typst_utils::hash128(&b));
+        }
+    }
+
+    #[test]
+    fn test_value_hash_eq() {
+        // Arrays are ordered.
+        test_hash_eq(array![1, 2], array![1, 2], true);
+        test_hash_eq(array![1, 2], array![2, 1], false);
+        test_hash_eq(array![array![1], 2], array![array![1], 2], true);
+
+        // Dictionaries are not.
+        test_hash_eq(dict!["a" => 1, "b" => 2], dict!["a" => 1, "b" => 2], true);
+        test_hash_eq(dict!["a" => 1, "b" => 2], dict!["b" => 2, "a" => 1], true);
+        test_hash_eq(dict!["a" => 1, "b" => 2], dict!["a" => 2, "b" => 1], false);
+        test_hash_eq(dict!["a" => 1], dict!["a" => 1, "b" => 2], false);
+        test_hash_eq(
+            dict!["x" => dict!["a" => 1, "b" => 2], "y" => array![3]],
+            dict!["y" => array![3], "x" => dict!["b" => 2, "a" => 1]],
+            true,
+        );
+        test_hash_eq(
+            array![dict!["a" => 1, "b" => 2]],
+            array![dict!["b" => 2, "a" => 1]],
+            true,
+        );
+    }
 }
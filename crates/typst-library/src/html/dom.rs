@@ -621,6 +621,7 @@ pub mod attr {
         charset
         content
         href
+        id
         name
         value
         role
@@ -4,11 +4,23 @@ mod dom;
 
 pub use self::dom::*;
 
-use ecow::EcoString;
+use ecow::{eco_format, EcoString};
 
-use crate::foundations::{category, elem, Category, Content, Module, Scope};
+use crate::foundations::{category, elem, Category, Content, Label, Module, Scope};
 
 /// HTML output.
+///
+/// HTML export produces semantic markup instead of reproducing the page
+/// layout. Paragraphs become `<p>` elements, headings map to `<h2>`–`<h6>` by
+/// their level, and links become `<a>` elements. The browser is then in charge
+/// of styling and reflowing the text. This means that the output only
+/// approximates the paged document: page setup, exact positioning, and most
+/// text styling are not carried over, and elements without an HTML
+/// counterpart are ignored with a warning.
+///
+/// Content that cannot be expressed semantically, such as a plot or a
+/// diagram, can be wrapped in `html.frame`. It is then laid out as in paged
+/// export and embedded as an inline SVG with absolute positioning.
 #[category]
 pub static HTML: Category;
 
@@ -21,6 +33,25 @@ pub fn module() -> Module {
     Module::new("html", html)
 }
 
+/// The `id` attribute under which a labelled element is exported.
+///
+/// Whitespace is not allowed in HTML ids, so it is percent-encoded together
+/// with the percent sign itself. This way, distinct labels always map to
+/// distinct ids and links can refer to an element by its label alone.
+pub fn label_id(label: Label) -> EcoString {
+    let mut id = EcoString::new();
+    for c in label.resolve().chars() {
+        if c.is_whitespace() || c == '%' {
+            for byte in c.encode_utf8(&mut [0; 4]).bytes() {
+                id.push_str(&eco_format!("%{byte:02X}"));
+            }
+        } else {
+            id.push(c);
+        }
+    }
+    id
+}
+
 /// A HTML element that can contain Typst content.
 #[elem(name = "elem")]
 pub struct HtmlElem {
@@ -13,7 +13,7 @@ use crate::foundations::{Content, Label, Repr, Selector};
 use crate::html::{HtmlElement, HtmlNode};
 use crate::introspection::{Location, Tag};
 use crate::layout::{Frame, FrameItem, Page, Point, Position, Transform};
-use crate::model::Numbering;
+use crate::model::{Numbering, RefElem};
 
 /// Can be queried for elements and their positions.
 #[derive(Default, Clone)]
@@ -64,6 +64,39 @@ impl Introspector {
         self.elems.iter().map(|(c, _)| c)
     }
 
+    /// Iterates over all labels in the document, in no particular order,
+    /// together with the positions of the elements that carry them.
+    ///
+    /// The positions are in document order. A label with multiple positions
+    /// cannot be referenced. The references themselves can be found by
+    /// querying for [`RefElem`](crate::model::RefElem)s.
+    pub fn labels(&self) -> impl Iterator<Item = (Label, Vec<Position>)> + '_ {
+        self.labels.0.iter().map(|(&label, indices)| {
+            let positions = indices.iter().map(|&idx| self.get_pos_by_idx(idx));
+            (label, positions.collect())
+        })
+    }
+
+    /// Iterates over all references in the document, in document order,
+    /// together with their positions, their target labels and the positions
+    /// of their targets.
+    ///
+    /// The target position is `None` if the label is not defined or defined
+    /// multiple times. Such a reference may still resolve to a bibliography
+    /// entry.
+    pub fn references(
+        &self,
+    ) -> impl Iterator<Item = (Position, Label, Option<Position>)> + '_ {
+        self.elems.iter().filter_map(|(elem, pos)| {
+            let target = elem.to_packed::<RefElem>()?.target;
+            let resolved = match self.labels.get(&target) {
+                &[idx] => Some(self.get_pos_by_idx(idx)),
+                _ => None,
+            };
+            Some((*pos, target, resolved))
+        })
+    }
+
     /// Retrieves the element with the given index.
     #[track_caller]
     fn get_by_idx(&self, idx: usize) -> &Content {
@@ -518,3 +551,70 @@ impl IntrospectorBuilder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use typst_utils::PicoStr;
+
+    use super::*;
+    use crate::foundations::{NativeElement, Smart};
+    use crate::layout::{Abs, Size};
+    use crate::model::HeadingElem;
+
+    fn label(name: &str) -> Label {
+        Label::new(PicoStr::intern(name))
+    }
+
+    fn point(y: f64) -> Point {
+        Point::with_y(Abs::pt(y))
+    }
+
+    #[test]
+    fn test_introspector_labels_and_references() {
+        let heading = |name, hash| {
+            HeadingElem::new(Content::empty())
+                .pack()
+                .labelled(label(name))
+                .located(Location::new(hash))
+        };
+        let reference =
+            |name, hash| RefElem::new(label(name)).pack().located(Location::new(hash));
+
+        let mut frame = Frame::soft(Size::splat(Abs::pt(100.0)));
+        for (y, elem) in [
+            (10.0, heading("intro", 1)),
+            (20.0, heading("twice", 2)),
+            (30.0, heading("twice", 3)),
+            (40.0, reference("intro", 4)),
+            (50.0, reference("twice", 5)),
+            (60.0, reference("missing", 6)),
+        ] {
+            frame.push(point(y), FrameItem::Tag(Tag::Start(elem)));
+        }
+
+        let page = Page {
+            frame,
+            fill: Smart::Auto,
+            numbering: None,
+            supplement: Content::empty(),
+            number: 1,
+        };
+        let introspector = Introspector::paged(&[page]);
+        let at = |y| Position { page: NonZeroUsize::ONE, point: point(y) };
+
+        let labels: HashMap<_, _> = introspector.labels().collect();
+        assert_eq!(labels.len(), 2);
+        assert_eq!(labels[&label("intro")], [at(10.0)]);
+        assert_eq!(labels[&label("twice")], [at(20.0), at(30.0)]);
+
+        let references: Vec<_> = introspector.references().collect();
+        assert_eq!(
+            references,
+            [
+                (at(40.0), label("intro"), Some(at(10.0))),
+                (at(50.0), label("twice"), None),
+                (at(60.0), label("missing"), None),
+            ]
+        );
+    }
+}
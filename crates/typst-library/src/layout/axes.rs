@@ -99,23 +99,40 @@ impl<T: Default> Axes<T> {
     }
 }
 
+/// Component-wise comparisons.
+///
+/// For sizes, infinite components compare like any other value: An infinite
+/// bound leaves its side unconstrained, so `min` and `clamp` with an infinite
+/// region return the finite component while `max` returns the infinite one.
+/// Components can't be NaN since lengths replace NaN with zero.
 impl<T: Ord> Axes<T> {
     /// The component-wise minimum of this and another instance.
     pub fn min(self, other: Self) -> Self {
         Self { x: self.x.min(other.x), y: self.y.min(other.y) }
     }
 
-    /// The component-wise minimum of this and another instance.
+    /// The component-wise maximum of this and another instance.
     pub fn max(self, other: Self) -> Self {
         Self { x: self.x.max(other.x), y: self.y.max(other.y) }
     }
 
+    /// Restrict each component to the range spanned by the respective
+    /// components of `min` and `max`.
+    ///
+    /// Panics if a component of `min` is greater than that of `max`.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self {
+            x: self.x.clamp(min.x, max.x),
+            y: self.y.clamp(min.y, max.y),
+        }
+    }
+
     /// The minimum of width and height.
     pub fn min_by_side(self) -> T {
         self.x.min(self.y)
     }
 
-    /// The minimum of width and height.
+    /// The maximum of width and height.
     pub fn max_by_side(self) -> T {
         self.x.max(self.y)
     }
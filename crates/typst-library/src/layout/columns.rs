@@ -4,6 +4,7 @@ use crate::diag::SourceResult;
 use crate::engine::Engine;
 use crate::foundations::{elem, Content, NativeElement, Packed, Show, StyleChain};
 use crate::layout::{BlockElem, Length, Ratio, Rel};
+use crate::visualize::Stroke;
 
 /// Separates a region into multiple equally sized columns.
 ///
@@ -53,6 +54,26 @@ pub struct ColumnsElem {
     #[default(Ratio::new(0.04).into())]
     pub gutter: Rel<Length>,
 
+    /// How to [stroke] a vertical rule centered in each gutter.
+    ///
+    /// A rule is drawn between two columns only if the second one has
+    /// content, so there is no rule after the last used column. The rule spans
+    /// the height of the column area, i.e. the taller of the columns if the
+    /// height isn't fixed by the container.
+    ///
+    /// This also applies to [page-level columns]($page.columns) when set with
+    /// a set rule.
+    ///
+    /// ```example
+    /// #set page(height: 120pt)
+    /// #columns(2, separator: 0.5pt + gray)[
+    ///   #lorem(40)
+    /// ]
+    /// ```
+    #[resolve]
+    #[fold]
+    pub separator: Option<Stroke>,
+
     /// The content that should be layouted into the columns.
     #[required]
     pub body: Content,
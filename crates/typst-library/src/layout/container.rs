@@ -1,3 +1,7 @@
+use std::num::NonZeroUsize;
+
+use typst_utils::NonZeroExt;
+
 use crate::diag::{bail, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
@@ -283,9 +287,10 @@ pub struct BlockElem {
     /// The spacing around the block. When `{auto}`, inherits the paragraph
     /// [`spacing`]($par.spacing).
     ///
-    /// For two adjacent blocks, the larger of the first block's `above` and the
-    /// second block's `below` spacing wins. Moreover, block spacing takes
-    /// precedence over paragraph [`spacing`]($par.spacing).
+    /// For two adjacent blocks, the larger of the first block's `below` and the
+    /// second block's `above` spacing wins. Moreover, block spacing takes
+    /// precedence over paragraph [`spacing`]($par.spacing). To add space on
+    /// top of the collapsed spacing, place a [`v`] between the blocks.
     ///
     /// Note that this is only a shorthand to set `above` and `below` to the
     /// same value. Since the values for `above` and `below` might differ, a
@@ -352,6 +357,15 @@ pub struct BlockElem {
     #[default(false)]
     pub sticky: bool,
 
+    /// How many lines of a directly following paragraph must fit into the
+    /// same region as this block if it is sticky.
+    ///
+    /// This is set by headings through their `keep-with-next` property.
+    #[internal]
+    #[ghost]
+    #[default(NonZeroUsize::ONE)]
+    pub sticky_lines: NonZeroUsize,
+
     /// The contents of the block.
     #[positional]
     #[borrowed]
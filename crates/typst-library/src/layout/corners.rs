@@ -64,6 +64,67 @@ impl<T> Corners<T> {
         }
     }
 
+    /// Apply a function to this and another instance componentwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typst_library::layout::Corners;
+    /// let max = Corners::new(1, 5, 2, 8).zip_map(Corners::splat(4), i32::max);
+    /// assert_eq!(max, Corners::new(4, 5, 4, 8));
+    /// ```
+    pub fn zip_map<F, V, U>(self, other: Corners<V>, mut f: F) -> Corners<U>
+    where
+        F: FnMut(T, V) -> U,
+    {
+        Corners {
+            top_left: f(self.top_left, other.top_left),
+            top_right: f(self.top_right, other.top_right),
+            bottom_right: f(self.bottom_right, other.bottom_right),
+            bottom_left: f(self.bottom_left, other.bottom_left),
+        }
+    }
+
+    /// Whether a condition is true for at least one of the components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typst_library::layout::Corners;
+    /// let radii = Corners::new(0, 0, 0, 3);
+    /// assert!(radii.any(|&v| v > 0));
+    /// assert!(!radii.any(|&v| v < 0));
+    /// ```
+    pub fn any<F>(self, mut f: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        f(&self.top_left)
+            || f(&self.top_right)
+            || f(&self.bottom_right)
+            || f(&self.bottom_left)
+    }
+
+    /// Whether a condition is true for all components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typst_library::layout::Corners;
+    /// let radii = Corners::splat(3);
+    /// assert!(radii.all(|&v| v == 3));
+    /// assert!(!Corners::new(3, 3, 3, 0).all(|&v| v == 3));
+    /// ```
+    pub fn all<F>(self, mut f: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        f(&self.top_left)
+            && f(&self.top_right)
+            && f(&self.bottom_right)
+            && f(&self.bottom_left)
+    }
+
     /// An iterator over the corners, starting with the top left corner,
     /// clockwise.
     pub fn iter(&self) -> impl Iterator<Item = &T> {
@@ -245,7 +306,7 @@ impl<T: Fold> Fold for Corners<Option<T>> {
         // Usually, folding an inner `None` with an `outer` prefers the
         // explicit `None`. However, here `None` means unspecified and thus
         // we want `outer`, so we use `fold_or` to opt into such behavior.
-        self.zip(outer).map(|(inner, outer)| inner.fold_or(outer))
+        self.zip_map(outer, |inner, outer| inner.fold_or(outer))
     }
 }
 
@@ -313,3 +374,34 @@ impl Corner {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_corners_zip_map() {
+        // Each corner is combined with the same corner of the other instance.
+        let names = Corners::new("top-left", "top-right", "bottom-right", "bottom-left");
+        let zipped = names.zip_map(Corners::new(1, 2, 3, 4), |name, i| (name, i));
+        assert_eq!(zipped.top_left, ("top-left", 1));
+        assert_eq!(zipped.top_right, ("top-right", 2));
+        assert_eq!(zipped.bottom_right, ("bottom-right", 3));
+        assert_eq!(zipped.bottom_left, ("bottom-left", 4));
+    }
+
+    #[test]
+    fn test_corners_any_all() {
+        // Every corner is checked, not just the first one.
+        for corner in
+            [Corner::TopLeft, Corner::TopRight, Corner::BottomRight, Corner::BottomLeft]
+        {
+            let mut corners = Corners::splat(false);
+            *corners.get_mut(corner) = true;
+            assert!(corners.any(|&v| v));
+            assert!(!corners.all(|&v| v));
+        }
+        assert!(!Corners::splat(false).any(|&v| v));
+        assert!(Corners::splat(true).all(|&v| v));
+    }
+}
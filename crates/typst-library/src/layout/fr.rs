@@ -143,3 +143,26 @@ impl Sum for Fr {
         Self(iter.map(|s| s.0).sum())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fr_clamp() {
+        let (lo, hi) = (Fr::new(1.0), Fr::new(3.0));
+        assert_eq!(Fr::new(0.5).clamp(lo, hi), lo);
+        assert_eq!(Fr::new(2.0).clamp(lo, hi), Fr::new(2.0));
+        assert_eq!(Fr::new(4.0).clamp(lo, hi), hi);
+    }
+
+    #[test]
+    fn test_fr_ordering() {
+        let mut frs = vec![Fr::new(2.0), Fr::zero(), Fr::new(-1.0), Fr::one()];
+        frs.sort();
+        assert_eq!(frs, [Fr::new(-1.0), Fr::zero(), Fr::one(), Fr::new(2.0)]);
+        assert_eq!(Fr::new(f64::NAN), Fr::zero());
+        assert_eq!(Fr::one().min(Fr::new(2.0)), Fr::one());
+        assert_eq!(Fr::one().max(Fr::new(2.0)), Fr::new(2.0));
+    }
+}
@@ -4,9 +4,11 @@ use std::fmt::{self, Debug, Formatter};
 use std::num::NonZeroUsize;
 use std::sync::Arc;
 
+use ecow::EcoString;
 use smallvec::SmallVec;
 use typst_syntax::Span;
 use typst_utils::{LazyHash, Numeric};
+use unicode_bidi::{bidi_class, BidiClass};
 
 use crate::foundations::{cast, dict, Dict, Label, StyleChain, Value};
 use crate::introspection::{Location, Tag};
@@ -18,6 +20,17 @@ use crate::text::TextItem;
 use crate::visualize::{Color, Curve, FixedStroke, Geometry, Image, Paint, Shape};
 
 /// A finished layout with items at fixed positions.
+///
+/// # Coordinate system
+/// All positions and sizes in a frame are given in points. The origin is the
+/// top-left corner of the frame, the x-axis points to the right and the
+/// y-axis points down. Each item's position is relative to the origin of the
+/// frame that directly contains it. For items in a [group](GroupItem), the
+/// group's transform is applied relative to the group's position, so a point
+/// in the group's frame maps to the outer frame by first applying the
+/// group's transform and then translating by the group's position. Use
+/// [`Frame::transform_of`] and [`Frame::transform_point`] to map points out
+/// of nested groups instead of relying on this manually.
 #[derive(Default, Clone, Hash)]
 pub struct Frame {
     /// The size of the frame.
@@ -146,6 +159,61 @@ impl Frame {
     }
 }
 
+/// Coordinate mapping.
+impl Frame {
+    /// The accumulated transform from the coordinate system of a nested group
+    /// to the coordinate system of this frame.
+    ///
+    /// The `path` lists item indices, one per nesting level, starting in this
+    /// frame. Each of them must refer to a [group](FrameItem::Group). Returns
+    /// `None` if an index is out of bounds or does not refer to a group. The
+    /// empty path yields the identity transform.
+    pub fn transform_of(&self, path: &[usize]) -> Option<Transform> {
+        let mut ts = Transform::identity();
+        let mut frame = self;
+        for &index in path {
+            let (pos, FrameItem::Group(group)) = frame.items.get(index)? else {
+                return None;
+            };
+            ts = ts
+                .pre_concat(Transform::translate(pos.x, pos.y))
+                .pre_concat(group.transform);
+            frame = &group.frame;
+        }
+        Some(ts)
+    }
+
+    /// Maps a point from the coordinate system of the nested group at `path`
+    /// to the coordinate system of this frame.
+    ///
+    /// See [`transform_of`](Self::transform_of) for the meaning of `path`.
+    pub fn transform_point(&self, path: &[usize], point: Point) -> Option<Point> {
+        self.transform_of(path).map(|ts| point.transform(ts))
+    }
+
+    /// Visits all items of this frame and its nested groups in order.
+    ///
+    /// The callback receives each item together with the transform from the
+    /// item's position to the coordinate system of this frame. A group is
+    /// visited before its contents. Its [clip curve](GroupItem::clip) lives in
+    /// the group's own coordinate system, that is, the reported transform
+    /// pre-concatenated with the group's [transform](GroupItem::transform).
+    pub fn walk(&self, mut f: impl FnMut(Transform, &FrameItem)) {
+        self.walk_impl(Transform::identity(), &mut f);
+    }
+
+    /// The recursive implementation of [`walk`](Self::walk).
+    fn walk_impl(&self, ts: Transform, f: &mut dyn FnMut(Transform, &FrameItem)) {
+        for (pos, item) in &self.items {
+            let ts = ts.pre_concat(Transform::translate(pos.x, pos.y));
+            f(ts, item);
+            if let FrameItem::Group(group) = item {
+                group.frame.walk_impl(ts.pre_concat(group.transform), f);
+            }
+        }
+    }
+}
+
 /// Insert items and subframes.
 impl Frame {
     /// The layer the next item will be added on. This corresponds to the number
@@ -267,6 +335,82 @@ impl Frame {
     }
 }
 
+/// Text extraction.
+impl Frame {
+    /// Extracts the text of this frame and its nested groups in reading order.
+    ///
+    /// Text runs are visited in the order in which they were laid out, which
+    /// matches the logical order of blocks, columns, and lines. A run belongs
+    /// to the current line if its baseline lies within the vertical bounds of
+    /// the line or if its own bounds contain a baseline of the line, so that
+    /// raised or lowered runs like superscripts do not start a new line.
+    /// Within a line, runs are stored in visual order, so consecutive
+    /// right-to-left runs are reversed to restore their logical order.
+    pub fn extract_text(&self) -> String {
+        let mut lines: Vec<Vec<(EcoString, Option<bool>)>> = vec![];
+        // The vertical bounds of the current line, from the cap height to the
+        // descender, and the range of its baselines.
+        let mut bounds: Option<(Abs, Abs, Abs, Abs)> = None;
+        self.walk(|ts, item| {
+            let FrameItem::Text(text) = item else { return };
+            let metrics = text.font.metrics();
+            let baseline = ts.ty;
+            let top = baseline - metrics.cap_height.at(text.size);
+            let bottom = baseline - metrics.descender.at(text.size);
+            bounds = match bounds {
+                Some((t, b, lo, hi))
+                    if (t..=b).contains(&baseline) || (top <= hi && lo <= bottom) =>
+                {
+                    Some((t.min(top), b.max(bottom), lo.min(baseline), hi.max(baseline)))
+                }
+                _ => {
+                    lines.push(vec![]);
+                    Some((top, bottom, baseline, baseline))
+                }
+            };
+            let rtl = text.text.chars().find_map(|c| match bidi_class(c) {
+                BidiClass::L => Some(false),
+                BidiClass::R | BidiClass::AL => Some(true),
+                _ => None,
+            });
+            lines.last_mut().unwrap().push((text.text.clone(), rtl));
+        });
+
+        let mut out = String::new();
+        for (i, runs) in lines.into_iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+
+            // Runs without strongly directional text (e.g. spaces) only
+            // belong to a right-to-left sequence if they are enclosed by one.
+            let mut resolved: Vec<(&str, bool)> = runs
+                .iter()
+                .enumerate()
+                .map(|(k, (text, rtl))| {
+                    let rtl = rtl.unwrap_or_else(|| {
+                        let strong = |run: &(EcoString, Option<bool>)| run.1;
+                        runs[..k].iter().rev().find_map(strong) == Some(true)
+                            && runs[k + 1..].iter().find_map(strong) == Some(true)
+                    });
+                    (text.as_str(), rtl)
+                })
+                .collect();
+
+            for chunk in resolved.chunk_by_mut(|a, b| a.1 == b.1) {
+                if chunk[0].1 {
+                    chunk.reverse();
+                }
+            }
+
+            for (text, _) in resolved {
+                out.push_str(text);
+            }
+        }
+        out
+    }
+}
+
 /// Modify the frame.
 impl Frame {
     /// Remove all items from the frame.
@@ -333,10 +477,14 @@ impl Frame {
     }
 
     /// Apply raw late-stage properties from the raw data.
+    ///
+    /// Link areas are placed in the background so that links from nested
+    /// content, which are added when the inner frames are processed, end up
+    /// on top and take precedence over the link of the surrounding content.
     pub fn post_process_raw(&mut self, dests: SmallVec<[Destination; 1]>, hide: bool) {
         if !self.is_empty() {
             let size = self.size;
-            self.push_multiple(
+            self.prepend_multiple(
                 dests
                     .into_iter()
                     .map(|dest| (Point::zero(), FrameItem::Link(dest, size))),
@@ -603,3 +751,174 @@ impl From<Position> for Dict {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use smallvec::smallvec;
+
+    use super::*;
+    use crate::foundations::Bytes;
+    use crate::layout::{Angle, Ratio};
+    use crate::model::Url;
+    use crate::text::{Font, Lang};
+
+    fn group(frame: Frame, transform: Transform) -> FrameItem {
+        FrameItem::Group(GroupItem { transform, ..GroupItem::new(frame) })
+    }
+
+    #[test]
+    fn test_frame_transform_point() {
+        let pt = Abs::pt;
+        let inner = Frame::soft(Size::new(pt(10.0), pt(10.0)));
+
+        let mut middle = Frame::soft(Size::new(pt(50.0), pt(50.0)));
+        middle.push(Point::zero(), FrameItem::Tag(Tag::End(Location::new(0), 0)));
+        middle.push(
+            Point::new(pt(5.0), pt(5.0)),
+            group(inner, Transform::scale(Ratio::new(2.0), Ratio::new(2.0))),
+        );
+
+        let mut outer = Frame::soft(Size::new(pt(100.0), pt(100.0)));
+        outer.push(Point::new(pt(20.0), pt(30.0)), group(middle, Transform::identity()));
+
+        let p = Point::new(pt(1.0), pt(2.0));
+        assert_eq!(outer.transform_point(&[], p), Some(p));
+        assert_eq!(outer.transform_point(&[0], p), Some(Point::new(pt(21.0), pt(32.0))));
+        assert_eq!(
+            outer.transform_point(&[0, 1], p),
+            Some(Point::new(pt(27.0), pt(39.0)))
+        );
+
+        // Non-group items and out-of-bounds indices have no transform.
+        assert_eq!(outer.transform_point(&[0, 0], p), None);
+        assert_eq!(outer.transform_point(&[1], p), None);
+    }
+
+    #[test]
+    fn test_frame_walk() {
+        let pt = Abs::pt;
+        let mut inner = Frame::soft(Size::new(pt(10.0), pt(10.0)));
+        inner.push(
+            Point::new(pt(1.0), pt(1.0)),
+            FrameItem::Tag(Tag::End(Location::new(0), 0)),
+        );
+
+        let mut outer = Frame::soft(Size::new(pt(100.0), pt(100.0)));
+        outer.push(Point::zero(), FrameItem::Tag(Tag::End(Location::new(1), 0)));
+        outer.push(
+            Point::new(pt(20.0), pt(30.0)),
+            group(inner, Transform::scale(Ratio::new(2.0), Ratio::new(2.0))),
+        );
+
+        let mut visited = vec![];
+        outer.walk(|ts, item| {
+            visited
+                .push((matches!(item, FrameItem::Group(_)), Point::zero().transform(ts)))
+        });
+
+        assert_eq!(
+            visited,
+            [
+                (false, Point::zero()),
+                (true, Point::new(pt(20.0), pt(30.0))),
+                (false, Point::new(pt(22.0), pt(32.0))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_frame_extract_text() {
+        let pt = Abs::pt;
+        let data = typst_dev_assets::fonts().next().unwrap();
+        let font = Font::new(Bytes::from_static(data), 0).unwrap();
+        let text = |text: &str| {
+            FrameItem::Text(TextItem {
+                font: font.clone(),
+                size: pt(10.0),
+                fill: Color::BLACK.into(),
+                stroke: None,
+                lang: Lang::ENGLISH,
+                region: None,
+                text: text.into(),
+                glyphs: vec![],
+            })
+        };
+
+        // The second line mixes left-to-right and right-to-left runs, which
+        // are laid out in visual order.
+        let mut line = Frame::soft(Size::new(pt(100.0), pt(10.0)));
+        line.push(Point::new(pt(0.0), pt(8.0)), text("Say "));
+        line.push(Point::new(pt(20.0), pt(8.0)), text("עולם"));
+        line.push(Point::new(pt(40.0), pt(8.0)), text(" "));
+        line.push(Point::new(pt(45.0), pt(8.0)), text("שלום"));
+        line.push(Point::new(pt(65.0), pt(8.0)), text(" now"));
+
+        let mut frame = Frame::soft(Size::new(pt(100.0), pt(30.0)));
+        frame.push(Point::new(pt(0.0), pt(8.0)), text("Hello "));
+        frame.push(Point::new(pt(30.0), pt(8.0)), text("world"));
+        frame.push(Point::new(pt(0.0), pt(12.0)), group(line, Transform::identity()));
+
+        assert_eq!(frame.extract_text(), "Hello world\nSay שלום עולם now");
+
+        // Raised and lowered runs stay on their line, also at its start.
+        let small = |body: &str| match text(body) {
+            FrameItem::Text(item) => FrameItem::Text(TextItem { size: pt(6.0), ..item }),
+            _ => unreachable!(),
+        };
+        let mut frame = Frame::soft(Size::new(pt(100.0), pt(40.0)));
+        frame.push(Point::new(pt(0.0), pt(3.0)), small("1"));
+        frame.push(Point::new(pt(4.0), pt(8.0)), text("Note"));
+        frame.push(Point::new(pt(30.0), pt(3.0)), small("2"));
+        frame.push(Point::new(pt(34.0), pt(10.0)), small("x"));
+        frame.push(Point::new(pt(0.0), pt(30.0)), text("Next"));
+        assert_eq!(frame.extract_text(), "1Note2x\nNext");
+    }
+
+    #[test]
+    fn test_frame_nested_links() {
+        let pt = Abs::pt;
+        let url = |url: &str| Destination::Url(Url::new(url).unwrap());
+        let links = |frame: &Frame| {
+            let mut links = vec![];
+            frame.walk(|_, item| {
+                if let FrameItem::Link(Destination::Url(dest), _) = item {
+                    links.push(dest.as_str().to_owned());
+                }
+            });
+            links
+        };
+
+        // Text in a link within a link carries both destinations, with the
+        // inner one last, so that it is on top.
+        let mut text = Frame::soft(Size::new(pt(10.0), pt(10.0)));
+        text.push(Point::zero(), FrameItem::Tag(Tag::End(Location::new(0), 0)));
+        text.post_process_raw(smallvec![url("outer"), url("inner")], false);
+        assert_eq!(links(&text), ["outer", "inner"]);
+
+        // A link around a frame that already contains a link is placed
+        // below it.
+        let mut inner = Frame::soft(Size::new(pt(10.0), pt(10.0)));
+        inner.push(Point::zero(), FrameItem::Tag(Tag::End(Location::new(0), 0)));
+        inner.post_process_raw(smallvec![url("inner")], false);
+        let mut outer = Frame::soft(Size::new(pt(20.0), pt(20.0)));
+        outer.push(Point::zero(), group(inner, Transform::identity()));
+        outer.post_process_raw(smallvec![url("outer")], false);
+        assert_eq!(links(&outer), ["outer", "inner"]);
+    }
+
+    #[test]
+    fn test_frame_transform_is_y_down() {
+        let pt = Abs::pt;
+        let mut frame = Frame::soft(Size::new(pt(100.0), pt(100.0)));
+        frame.push(
+            Point::new(pt(50.0), pt(50.0)),
+            group(Frame::soft(Size::zero()), Transform::rotate(Angle::deg(90.0))),
+        );
+
+        // A clockwise quarter turn (in a y-down system) maps the x-axis of the
+        // group onto the y-axis of the frame.
+        let p = frame.transform_point(&[0], Point::with_x(pt(10.0))).unwrap();
+        assert!((p.x - pt(50.0)).to_pt().abs() < 1e-9);
+        assert!((p.y - pt(60.0)).to_pt().abs() < 1e-9);
+    }
+}
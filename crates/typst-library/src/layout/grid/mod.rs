@@ -132,7 +132,9 @@ use crate::visualize::{Paint, Stroke};
 /// setting the [`{grid}`'s `stroke` field]($grid.stroke). When multiple of
 /// these settings are present and conflict, the `hline` and `vline` settings
 /// take the highest precedence, followed by the `cell` settings, and finally
-/// the `grid` settings.
+/// the `grid` settings. If several lines overlap at the same position, the
+/// one specified last among the grid's children wins where they overlap. A
+/// line with `{stroke: none}` can thus remove parts of an earlier line.
 ///
 /// Furthermore, strokes of a repeated grid header or footer will take
 /// precedence over regular cell strokes.
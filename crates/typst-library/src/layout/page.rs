@@ -15,7 +15,7 @@ use crate::foundations::{
 use crate::introspection::Introspector;
 use crate::layout::{
     Abs, Alignment, FlushElem, Frame, HAlignment, Length, OuterVAlignment, Ratio, Rel,
-    Sides, SpecificAlignment,
+    Sides, Size, SpecificAlignment,
 };
 use crate::model::{DocumentInfo, Numbering};
 use crate::text::LocalName;
@@ -182,6 +182,32 @@ pub struct PageElem {
     #[ghost]
     pub columns: NonZeroUsize,
 
+    /// Whether to stretch the spacing between paragraphs and blocks such that
+    /// the content of a page (or column) reaches its bottom edge.
+    ///
+    /// - `{none}`: Pages have a ragged bottom.
+    /// - A length: The spacing between paragraphs and blocks is stretched
+    ///   evenly, but every gap grows by at most this amount.
+    ///
+    /// Only automatic spacing is stretched. Explicitly set spacing, like the
+    /// one around headings, and the spacing between lines stays fixed. The
+    /// last page of the document and pages ending with an explicit break keep
+    /// a ragged bottom.
+    ///
+    /// ```example
+    /// #set page(height: 120pt, flush-bottom: 1em)
+    /// #set par(spacing: 0.6em)
+    /// #lorem(10)
+    ///
+    /// #lorem(8)
+    ///
+    /// #lorem(12)
+    /// #pagebreak(weak: true)
+    /// ```
+    #[resolve]
+    #[ghost]
+    pub flush_bottom: Option<Length>,
+
     /// The page's background fill.
     ///
     /// Setting this to something non-transparent instructs the printer to color
@@ -463,6 +489,18 @@ pub struct PagedDocument {
     pub introspector: Introspector,
 }
 
+impl PagedDocument {
+    /// The number of physical pages in the document.
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// The sizes of the document's pages, in order.
+    pub fn page_sizes(&self) -> impl Iterator<Item = Size> + '_ {
+        self.pages.iter().map(|page| page.frame.size())
+    }
+}
+
 /// A finished page.
 #[derive(Debug, Clone)]
 pub struct Page {
@@ -733,6 +771,11 @@ pub enum Parity {
 
 impl Parity {
     /// Whether the given number matches the parity.
+    ///
+    /// When called with the number of pages laid out so far, this tells
+    /// whether a blank page must be inserted so that the next page has the
+    /// desired parity. For example, after one page, an `Odd` page break needs
+    /// a blank second page for the content to continue on the third.
     pub fn matches(self, number: usize) -> bool {
         match self {
             Self::Even => number % 2 == 0,
@@ -959,6 +1002,48 @@ papers! {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parity_matches() {
+        // After an odd number of pages, the next page is even, so an odd
+        // page break needs a blank page while an even one doesn't.
+        assert!(Parity::Odd.matches(1));
+        assert!(Parity::Odd.matches(3));
+        assert!(!Parity::Even.matches(1));
+        assert!(!Parity::Even.matches(3));
+
+        // After an even number of pages, it's the other way around.
+        assert!(Parity::Even.matches(0));
+        assert!(Parity::Even.matches(2));
+        assert!(!Parity::Odd.matches(0));
+        assert!(!Parity::Odd.matches(2));
+    }
+
+    #[test]
+    fn test_paged_document_page_sizes() {
+        let page = |width, height| Page {
+            frame: Frame::hard(Size::new(Abs::pt(width), Abs::pt(height))),
+            fill: Smart::Auto,
+            numbering: None,
+            supplement: Content::empty(),
+            number: 1,
+        };
+
+        let doc = PagedDocument {
+            pages: vec![page(100.0, 200.0), page(300.0, 50.0)],
+            ..Default::default()
+        };
+
+        assert_eq!(PagedDocument::default().page_count(), 0);
+        assert_eq!(doc.page_count(), 2);
+        assert_eq!(
+            doc.page_sizes().collect::<Vec<_>>(),
+            [
+                Size::new(Abs::pt(100.0), Abs::pt(200.0)),
+                Size::new(Abs::pt(300.0), Abs::pt(50.0))
+            ]
+        );
+    }
+
     #[test]
     fn test_paged_document_is_send_and_sync() {
         fn ensure_send_and_sync<T: Send + Sync>() {}
@@ -45,11 +45,22 @@ impl Point {
         Self { x: self.x.min(other.x), y: self.y.min(other.y) }
     }
 
-    /// The component-wise minimum of this and another point.
+    /// The component-wise maximum of this and another point.
     pub fn max(self, other: Self) -> Self {
         Self { x: self.x.max(other.x), y: self.y.max(other.y) }
     }
 
+    /// Restrict each coordinate to the range spanned by the respective
+    /// coordinates of `min` and `max`.
+    ///
+    /// Panics if a coordinate of `min` is greater than that of `max`.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self {
+            x: self.x.clamp(min.x, max.x),
+            y: self.y.clamp(min.y, max.y),
+        }
+    }
+
     /// Maps the point with the given function.
     pub fn map(self, f: impl Fn(Abs) -> Abs) -> Self {
         Self { x: f(self.x), y: f(self.y) }
@@ -166,3 +177,17 @@ typst_utils::assign_impl!(Point += Point);
 typst_utils::assign_impl!(Point -= Point);
 typst_utils::assign_impl!(Point *= f64);
 typst_utils::assign_impl!(Point /= f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_clamp() {
+        let lo = Point::zero();
+        let hi = Point::new(Abs::pt(10.0), Abs::pt(10.0));
+        let point = Point::new(Abs::pt(-3.0), Abs::pt(4.0));
+        assert_eq!(point.clamp(lo, hi), Point::with_y(Abs::pt(4.0)));
+        assert_eq!(point.max(lo), point.clamp(lo, hi));
+    }
+}
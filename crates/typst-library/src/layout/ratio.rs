@@ -151,3 +151,18 @@ typst_utils::assign_impl!(Ratio -= Ratio);
 typst_utils::assign_impl!(Ratio *= Ratio);
 typst_utils::assign_impl!(Ratio *= f64);
 typst_utils::assign_impl!(Ratio /= f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ratio_clamp() {
+        let (lo, hi) = (Ratio::zero(), Ratio::one());
+        assert_eq!(Ratio::new(-0.5).clamp(lo, hi), lo);
+        assert_eq!(Ratio::new(0.25).clamp(lo, hi), Ratio::new(0.25));
+        assert_eq!(Ratio::new(1.5).clamp(lo, hi), hi);
+        assert!(Ratio::new(0.25) < Ratio::new(0.5));
+        assert_eq!(Ratio::new(0.25).max(Ratio::new(0.5)), Ratio::new(0.5));
+    }
+}
@@ -38,6 +38,10 @@ impl From<Region> for Regions<'_> {
 /// same width, namely `self.size.x`. This means that it is not
 /// currently possible to, for instance, have content wrap to the
 /// side of a floating element.
+///
+/// Layout results are memoized with the regions as part of the cache key.
+/// A cached fragment is thus only reused for regions that hash identically,
+/// there is no notion of a fragment being valid for a range of sizes.
 #[derive(Copy, Clone, Hash)]
 pub struct Regions<'a> {
     /// The remaining size of the first region.
@@ -243,6 +243,30 @@ impl<T: Numeric> Add<Ratio> for Rel<T> {
     }
 }
 
+impl<T: Numeric> Sub<T> for Ratio {
+    type Output = Rel<T>;
+
+    fn sub(self, other: T) -> Self::Output {
+        Rel::from(self) - Rel::from(other)
+    }
+}
+
+impl<T: Numeric> Sub<T> for Rel<T> {
+    type Output = Self;
+
+    fn sub(self, other: T) -> Self::Output {
+        self - Rel::from(other)
+    }
+}
+
+impl<T: Numeric> Sub<Ratio> for Rel<T> {
+    type Output = Self;
+
+    fn sub(self, other: Ratio) -> Self::Output {
+        self - Rel::from(other)
+    }
+}
+
 impl<T> Resolve for Rel<T>
 where
     T: Resolve + Numeric,
@@ -268,3 +292,32 @@ cast! {
     Rel<Abs>,
     self => self.map(Length::from).into_value(),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rel_neg() {
+        let rel = Ratio::new(0.2) + Length::from(Abs::pt(5.0));
+        assert_eq!(-rel, Rel::new(Ratio::new(-0.2), Length::from(Abs::pt(-5.0))));
+    }
+
+    #[test]
+    fn test_rel_sub() {
+        let a = Ratio::new(0.5) + Length::from(Abs::cm(1.0));
+        let b = a - Length::from(Abs::mm(2.0));
+        assert_eq!(b.rel, Ratio::new(0.5));
+        assert_eq!(b.abs, Length::from(Abs::cm(1.0) - Abs::mm(2.0)));
+        assert_eq!(a - a, Rel::zero());
+        assert_eq!((a - Ratio::new(0.5)).rel, Ratio::zero());
+    }
+
+    #[test]
+    fn test_rel_scale() {
+        let rel = Ratio::new(0.5) + Length::from(Abs::pt(4.0));
+        assert_eq!(rel * 2.0, Rel::new(Ratio::one(), Length::from(Abs::pt(8.0))));
+        assert_eq!(2.0 * rel, rel * 2.0);
+        assert_eq!(rel / 2.0, Rel::new(Ratio::new(0.25), Length::from(Abs::pt(2.0))));
+    }
+}
@@ -75,6 +75,61 @@ impl<T> Sides<T> {
         }
     }
 
+    /// Apply a function to this and another instance componentwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typst_library::layout::Sides;
+    /// let sum = Sides::new(1, 2, 3, 4).zip_map(Sides::splat(10), |a, b| a + b);
+    /// assert_eq!(sum, Sides::new(11, 12, 13, 14));
+    /// ```
+    pub fn zip_map<F, V, U>(self, other: Sides<V>, mut f: F) -> Sides<U>
+    where
+        F: FnMut(T, V) -> U,
+    {
+        Sides {
+            left: f(self.left, other.left),
+            top: f(self.top, other.top),
+            right: f(self.right, other.right),
+            bottom: f(self.bottom, other.bottom),
+        }
+    }
+
+    /// Whether a condition is true for at least one of the components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typst_library::layout::Sides;
+    /// let sides = Sides::new(0, 0, 5, 0);
+    /// assert!(sides.any(|&v| v > 0));
+    /// assert!(!sides.any(|&v| v < 0));
+    /// ```
+    pub fn any<F>(self, mut f: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        f(&self.left) || f(&self.top) || f(&self.right) || f(&self.bottom)
+    }
+
+    /// Whether a condition is true for all components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use typst_library::layout::Sides;
+    /// let sides = Sides::new(1, 2, 3, 4);
+    /// assert!(sides.all(|&v| v > 0));
+    /// assert!(!sides.all(|&v| v > 1));
+    /// ```
+    pub fn all<F>(self, mut f: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        f(&self.left) && f(&self.top) && f(&self.right) && f(&self.bottom)
+    }
+
     /// An iterator over the sides, starting with the left side, clockwise.
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         [&self.left, &self.top, &self.right, &self.bottom].into_iter()
@@ -119,10 +174,7 @@ impl Sides<Rel<Abs>> {
 
     /// Whether all sides are zero.
     pub fn is_zero(&self) -> bool {
-        self.left.is_zero()
-            && self.top.is_zero()
-            && self.right.is_zero()
-            && self.bottom.is_zero()
+        self.as_ref().all(|v| v.is_zero())
     }
 }
 
@@ -258,7 +310,7 @@ impl<T: Fold> Fold for Sides<Option<T>> {
         // Usually, folding an inner `None` with an `outer` prefers the
         // explicit `None`. However, here `None` means unspecified and thus
         // we want `outer`, so we use `fold_or` to opt into such behavior.
-        self.zip(outer).map(|(inner, outer)| inner.fold_or(outer))
+        self.zip_map(outer, |inner, outer| inner.fold_or(outer))
     }
 }
 
@@ -341,3 +393,32 @@ cast! {
         _ => bail!("cannot convert this alignment to a side"),
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sides_zip_map() {
+        // Each side is combined with the same side of the other instance.
+        let names = Sides::new("left", "top", "right", "bottom");
+        let zipped = names.zip_map(Sides::new(1, 2, 3, 4), |name, i| (name, i));
+        assert_eq!(zipped.left, ("left", 1));
+        assert_eq!(zipped.top, ("top", 2));
+        assert_eq!(zipped.right, ("right", 3));
+        assert_eq!(zipped.bottom, ("bottom", 4));
+    }
+
+    #[test]
+    fn test_sides_any_all() {
+        // Every side is checked, not just the first one.
+        for side in [Side::Left, Side::Top, Side::Right, Side::Bottom] {
+            let mut sides = Sides::splat(false);
+            *sides.get_mut(side) = true;
+            assert!(sides.any(|&v| v));
+            assert!(!sides.all(|&v| v));
+        }
+        assert!(!Sides::splat(false).any(|&v| v));
+        assert!(Sides::splat(true).all(|&v| v));
+    }
+}
@@ -85,3 +85,25 @@ typst_utils::assign_impl!(Size -= Size);
 typst_utils::assign_impl!(Size += Size);
 typst_utils::assign_impl!(Size *= f64);
 typst_utils::assign_impl!(Size /= f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_min_max_with_infinity() {
+        let size = Size::new(Abs::pt(10.0), Abs::pt(20.0));
+        let region = Size::new(Abs::pt(15.0), Abs::inf());
+        assert_eq!(size.min(region), Size::new(Abs::pt(10.0), Abs::pt(20.0)));
+        assert_eq!(size.max(region), Size::new(Abs::pt(15.0), Abs::inf()));
+    }
+
+    #[test]
+    fn test_size_clamp() {
+        let lo = Size::new(Abs::pt(5.0), Abs::zero());
+        let hi = Size::new(Abs::pt(10.0), Abs::inf());
+        let size = Size::new(Abs::pt(12.0), Abs::pt(100.0));
+        assert_eq!(size.clamp(lo, hi), Size::new(Abs::pt(10.0), Abs::pt(100.0)));
+        assert_eq!(Size::zero().clamp(lo, hi), lo);
+    }
+}
@@ -101,7 +101,8 @@ pub struct VElem {
     /// If `{true}`, the spacing collapses at the start or end of a flow.
     /// Moreover, from multiple adjacent weak spacings all but the largest one
     /// collapse. Weak spacings will always collapse adjacent paragraph spacing,
-    /// even if the paragraph spacing is larger.
+    /// even if the paragraph spacing is larger. Weak spacing thus vanishes at
+    /// the top of a page or column, but survives between two blocks.
     ///
     /// ```example
     /// The following theorem is
@@ -96,8 +96,15 @@ pub struct RotateElem {
     /// the original content. If set to `{true}`, the bounding box will take the
     /// rotation of the content into account and adjust the layout accordingly.
     ///
+    /// This also holds for rotated content in a [`box`] within running text:
+    /// With reflow, the line grows to fit the rotated content instead of
+    /// overlapping with adjacent lines.
+    ///
     /// ```example
     /// Hello #rotate(90deg, reflow: true)[World]!
+    ///
+    /// A line with a #box(rotate(90deg, reflow: true)[label])
+    /// in it does not overlap with its neighbors.
     /// ```
     #[default(false)]
     pub reflow: bool,
@@ -32,10 +32,12 @@ use typst_syntax::{FileId, Source, Span};
 use typst_utils::{LazyHash, SmallBitSet};
 
 use crate::diag::FileResult;
-use crate::foundations::{Array, Bytes, Datetime, Dict, Module, Scope, Styles, Value};
-use crate::layout::{Alignment, Dir};
-use crate::text::{Font, FontBook};
-use crate::visualize::Color;
+use crate::foundations::{
+    Array, Bytes, Datetime, Dict, Module, Scope, Smart, Styles, Value,
+};
+use crate::layout::{Alignment, Dir, Length, Margin, PageElem, Paper, Rel};
+use crate::text::{Font, FontBook, FontList, TextElem, TextSize};
+use crate::visualize::{Color, Paint};
 
 /// The environment in which typesetting occurs.
 ///
@@ -173,6 +175,12 @@ impl Default for Library {
 pub struct LibraryBuilder {
     inputs: Option<Dict>,
     features: Features,
+    paper: Option<Paper>,
+    margin: Option<Rel<Length>>,
+    font: Option<FontList>,
+    text_size: Option<Length>,
+    text_fill: Option<Paint>,
+    page_fill: Option<Paint>,
 }
 
 impl LibraryBuilder {
@@ -190,19 +198,86 @@ impl LibraryBuilder {
         self
     }
 
+    /// Configure the paper size of pages whose size is not set explicitly.
+    ///
+    /// Defaults to A4. A `set page(..)` rule in the document always wins.
+    pub fn with_paper(mut self, paper: Paper) -> Self {
+        self.paper = Some(paper);
+        self
+    }
+
+    /// Configure the margins of pages whose margins are not set explicitly.
+    ///
+    /// Accepts absolute lengths as well as ratios of the page size. Defaults
+    /// to a size relative to the page. A `set page(..)` rule in the document
+    /// always wins.
+    pub fn with_margin(mut self, margin: impl Into<Rel<Length>>) -> Self {
+        self.margin = Some(margin.into());
+        self
+    }
+
+    /// Configure the font families used by text whose font is not set
+    /// explicitly.
+    ///
+    /// A `set text(..)` rule in the document always wins.
+    pub fn with_font(mut self, font: FontList) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Configure the size of text whose size is not set explicitly.
+    ///
+    /// Defaults to 11pt. A `set text(..)` rule in the document always wins.
+    pub fn with_text_size(mut self, size: Length) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Configure the color of text whose fill is not set explicitly.
+    ///
+    /// Defaults to black. A `set text(..)` rule in the document always wins.
+    pub fn with_text_fill(mut self, fill: impl Into<Paint>) -> Self {
+        self.text_fill = Some(fill.into());
+        self
+    }
+
+    /// Configure the background of pages whose fill is not set explicitly.
+    ///
+    /// Together with [`with_text_fill`](Self::with_text_fill), this can be
+    /// used to produce dark-themed previews. A `set page(..)` rule in the
+    /// document always wins.
+    pub fn with_page_fill(mut self, fill: impl Into<Paint>) -> Self {
+        self.page_fill = Some(fill.into());
+        self
+    }
+
     /// Consumes the builder and returns a `Library`.
     pub fn build(self) -> Library {
         let math = math::module();
         let inputs = self.inputs.unwrap_or_default();
         let global = global(math.clone(), inputs, &self.features);
         let std = Value::Module(global.clone());
-        Library {
-            global,
-            math,
-            styles: Styles::new(),
-            std,
-            features: self.features,
+        let mut styles = Styles::new();
+        if let Some(paper) = self.paper {
+            styles.set(PageElem::set_width(Smart::Custom(paper.width().into())));
+            styles.set(PageElem::set_height(Smart::Custom(paper.height().into())));
+        }
+        if let Some(margin) = self.margin {
+            styles.set(PageElem::set_margin(Margin::splat(Some(Smart::Custom(margin)))));
+        }
+        if let Some(font) = self.font {
+            styles.set(TextElem::set_font(font));
         }
+        if let Some(size) = self.text_size {
+            styles.set(TextElem::set_size(TextSize(size)));
+        }
+        if let Some(fill) = self.text_fill {
+            styles.set(TextElem::set_fill(fill));
+        }
+        if let Some(fill) = self.page_fill {
+            styles.set(PageElem::set_fill(Smart::Custom(Some(fill))));
+        }
+        Library { global, math, styles, std, features: self.features }
     }
 }
 
@@ -297,3 +372,71 @@ fn prelude(global: &mut Scope) {
     global.define("horizon", Alignment::HORIZON);
     global.define("bottom", Alignment::BOTTOM);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::foundations::StyleChain;
+    use crate::layout::{Abs, Ratio, Sides};
+    use crate::text::FontFamily;
+
+    #[test]
+    fn test_library_fill_defaults() {
+        let library = Library::builder()
+            .with_page_fill(Color::BLACK)
+            .with_text_fill(Color::WHITE)
+            .build();
+        let base = StyleChain::new(&library.styles);
+        assert_eq!(PageElem::fill_in(base), &Smart::Custom(Some(Color::BLACK.into())));
+        assert_eq!(TextElem::fill_in(base), Color::WHITE.into());
+
+        // Set rules in the document take precedence over the defaults.
+        let mut document = Styles::new();
+        document.set(PageElem::set_fill(Smart::Custom(None)));
+        document.set(TextElem::set_fill(Color::RED.into()));
+        let styles = base.chain(&document);
+        assert_eq!(PageElem::fill_in(styles), &Smart::Custom(None));
+        assert_eq!(TextElem::fill_in(styles), Color::RED.into());
+    }
+
+    #[test]
+    fn test_library_page_and_text_defaults() {
+        let margin = |pt| Some(Smart::Custom(Rel::from(Length::from(Abs::pt(pt)))));
+        let family = |name: &str| FontList(vec![FontFamily::new(name)]);
+        let library = Library::builder()
+            .with_margin(Abs::pt(20.0))
+            .with_font(family("Inria Serif"))
+            .with_text_size(Abs::pt(14.0).into())
+            .build();
+        let base = StyleChain::new(&library.styles);
+        assert_eq!(PageElem::margin_in(base), Margin::splat(margin(20.0)));
+        assert_eq!(TextElem::font_in(base), &family("Inria Serif"));
+        assert_eq!(TextElem::size_in(base), Abs::pt(14.0));
+
+        // Set rules in the document take precedence over the defaults. A
+        // margin that is only set for one side keeps the default for the
+        // others.
+        let mut document = Styles::new();
+        document.set(PageElem::set_margin(Margin {
+            sides: Sides { left: margin(10.0), ..Sides::splat(None) },
+            two_sided: None,
+        }));
+        document.set(TextElem::set_font(family("Libertinus Serif")));
+        document.set(TextElem::set_size(TextSize(Abs::pt(8.0).into())));
+        let styles = base.chain(&document);
+        assert_eq!(
+            PageElem::margin_in(styles).sides,
+            Sides { left: margin(10.0), ..Sides::splat(margin(20.0)) },
+        );
+        assert_eq!(TextElem::font_in(styles), &family("Libertinus Serif"));
+        assert_eq!(TextElem::size_in(styles), Abs::pt(8.0));
+
+        // Margins may also be relative to the page.
+        let library = Library::builder().with_margin(Ratio::new(0.1)).build();
+        let base = StyleChain::new(&library.styles);
+        assert_eq!(
+            PageElem::margin_in(base),
+            Margin::splat(Some(Smart::Custom(Ratio::new(0.1).into()))),
+        );
+    }
+}
@@ -6,6 +6,10 @@ use crate::text::TextElem;
 
 /// Attaches an accent to a base.
 ///
+/// The accent is positioned horizontally using the font's top accent
+/// attachment points and vertically according to the font's math constants.
+/// The result is as wide as the base, so accents never disturb spacing.
+///
 /// # Example
 /// ```example
 /// $grave(a) = accent(a, `)$ \
@@ -7,6 +7,19 @@ use crate::text::TextElem;
 ///
 /// While matched delimiters scale by default, this can be used to scale
 /// unmatched delimiters and to control the delimiter scaling more precisely.
+///
+/// Since the delimiters are sized after measuring the enclosed content, this
+/// also works for annotating tall non-mathematical content, such as a brace
+/// spanning several stacked lines.
+///
+/// ```example
+/// $ lr(\{ #stack(
+///   spacing: 4pt,
+///   [First item],
+///   [Second item],
+///   [Third item],
+/// )) $
+/// ```
 #[elem(title = "Left/Right", Mathy)]
 pub struct LrElem {
     /// The size of the brackets, relative to the height of the wrapped content.
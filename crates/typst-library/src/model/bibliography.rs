@@ -21,7 +21,7 @@ use typst_syntax::{Span, Spanned};
 use typst_utils::{LazyHash, NonZeroExt, PicoStr};
 
 use crate::diag::{bail, error, At, FileError, HintedStrResult, SourceResult, StrResult};
-use crate::engine::Engine;
+use crate::engine::{Engine, Route};
 use crate::foundations::{
     cast, elem, ty, Args, Array, Bytes, CastInfo, Content, FromValue, IntoValue, Label,
     NativeElement, Packed, Reflect, Repr, Scope, Show, ShowSet, Smart, Str, StyleChain,
@@ -998,6 +998,7 @@ impl ElemRenderer<'_> {
         (self.routines.eval_string)(
             self.routines,
             self.world,
+            Route::default().track(),
             math,
             self.span,
             EvalMode::Math,
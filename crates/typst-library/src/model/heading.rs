@@ -180,6 +180,26 @@ pub struct HeadingElem {
     #[default(Smart::Auto)]
     pub hanging_indent: Smart<Length>,
 
+    /// How many lines of the following paragraph are kept on the same page
+    /// as the heading.
+    ///
+    /// Headings are [sticky]($block.sticky), so they never end up alone at
+    /// the bottom of a page. With this property, you can additionally require
+    /// that a number of lines of the following paragraph fit below the
+    /// heading. If they don't, the heading moves to the next page together
+    /// with the paragraph.
+    ///
+    /// ```example
+    /// >>> #set page(height: 120pt)
+    /// #set heading(keep-with-next: 3)
+    /// #lorem(18)
+    ///
+    /// = Chapter
+    /// #lorem(20)
+    /// ```
+    #[default(NonZeroUsize::ONE)]
+    pub keep_with_next: NonZeroUsize,
+
     /// The heading's title.
     #[required]
     pub body: Content,
@@ -319,6 +339,7 @@ impl ShowSet for Packed<HeadingElem> {
         out.set(BlockElem::set_above(Smart::Custom(above.into())));
         out.set(BlockElem::set_below(Smart::Custom(below.into())));
         out.set(BlockElem::set_sticky(true));
+        out.set(BlockElem::set_sticky_lines(self.keep_with_next(styles)));
         out
     }
 }
@@ -9,7 +9,7 @@ use crate::foundations::{
     cast, elem, Content, Label, NativeElement, Packed, Repr, Show, Smart, StyleChain,
     TargetElem,
 };
-use crate::html::{attr, tag, HtmlElem};
+use crate::html::{self, attr, tag, HtmlElem};
 use crate::introspection::Location;
 use crate::layout::Position;
 use crate::text::{Hyphenate, TextElem};
@@ -106,16 +106,25 @@ impl Show for Packed<LinkElem> {
         let dest = self.dest();
 
         Ok(if TargetElem::target_in(styles).is_html() {
-            if let LinkTarget::Dest(Destination::Url(url)) = dest {
+            let href = match dest {
+                LinkTarget::Dest(Destination::Url(url)) => Some(url.clone().into_inner()),
+                LinkTarget::Label(label) => {
+                    engine.introspector.query_label(*label).at(self.span())?;
+                    Some(eco_format!("#{}", html::label_id(*label)))
+                }
+                LinkTarget::Dest(_) => None,
+            };
+
+            if let Some(href) = href {
                 HtmlElem::new(tag::a)
-                    .with_attr(attr::href, url.clone().into_inner())
+                    .with_attr(attr::href, href)
                     .with_body(Some(body))
                     .pack()
                     .spanned(self.span())
             } else {
                 engine.sink.warn(warning!(
                     self.span(),
-                    "non-URL links are not yet supported by HTML export"
+                    "links to locations and positions are not yet supported by HTML export"
                 ));
                 body
             }
@@ -0,0 +1,111 @@
+use crate::diag::{bail, SourceResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    cast, elem, Content, IntoValue, Packed, Show, StyleChain, Styles, Synthesize,
+};
+use crate::introspection::Locatable;
+use crate::layout::{Alignment, Em, Length};
+
+/// A note in the page margin.
+///
+/// The note is placed in the margin next to the line in which it appears in
+/// the text, with its first baseline aligned to that line's baseline. When
+/// several notes on the same page would overlap, the later ones are pushed
+/// down until they fit.
+///
+/// By default, notes go into the outside margin, that is, the margin further
+/// away from the page's [binding]($page.binding). On two-sided pages, this
+/// alternates between the left and right margin.
+///
+/// # Example
+/// ```example
+/// >>> #set page(margin: (x: 80pt))
+/// The mitochondria is the
+/// powerhouse of the cell.
+/// #marginnote[Citation needed.]
+/// ```
+///
+/// The body of a note is laid out with the styles active where the note
+/// appears, so set and show-set rules around it apply as usual. For
+/// example, `{set marginnote(side: "inside")}` moves all following notes to
+/// the inside margin, and `{show marginnote: set text(0.8em)}` makes their
+/// text smaller.
+#[elem(name = "marginnote", title = "Margin Note", Locatable, Synthesize, Show)]
+pub struct MarginNoteElem {
+    /// In which margin to place the note.
+    ///
+    /// This is either `{"outside"}`, `{"inside"}`, `{left}`, or `{right}`.
+    ///
+    /// The physical sides `{left}` and `{right}` are only as wide as the
+    /// narrower of the two margins on two-sided pages, as the margins swap
+    /// places between left-hand and right-hand pages.
+    #[default(MarginNoteSide::Outside)]
+    pub side: MarginNoteSide,
+
+    /// The horizontal space between the note and the text area as well as
+    /// between the note and the edge of the page.
+    #[default(Em::new(1.0).into())]
+    pub clearance: Length,
+
+    /// The minimum vertical space between two notes in the same margin.
+    #[default(Em::new(0.5).into())]
+    pub gap: Length,
+
+    /// The content to put into the margin.
+    #[required]
+    pub body: Content,
+
+    /// The styles active where the note appears.
+    #[internal]
+    #[synthesized]
+    pub styles: Styles,
+}
+
+impl Synthesize for Packed<MarginNoteElem> {
+    fn synthesize(&mut self, _: &mut Engine, styles: StyleChain) -> SourceResult<()> {
+        // The note is laid out together with its page, where the styles at
+        // its position are no longer available.
+        self.push_styles(styles.to_map());
+        Ok(())
+    }
+}
+
+impl Show for Packed<MarginNoteElem> {
+    fn show(&self, _: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        // The note itself is laid out together with its page. In the flow,
+        // only its introspection tags remain to mark the anchor line.
+        Ok(Content::empty())
+    }
+}
+
+/// In which margin to place a margin note.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum MarginNoteSide {
+    /// The margin further away from the binding.
+    Outside,
+    /// The margin closer to the binding.
+    Inside,
+    /// The left margin.
+    Left,
+    /// The right margin.
+    Right,
+}
+
+cast! {
+    MarginNoteSide,
+    self => match self {
+        Self::Outside => "outside".into_value(),
+        Self::Inside => "inside".into_value(),
+        Self::Left => Alignment::LEFT.into_value(),
+        Self::Right => Alignment::RIGHT.into_value(),
+    },
+    /// The margin further away from the binding.
+    "outside" => Self::Outside,
+    /// The margin closer to the binding.
+    "inside" => Self::Inside,
+    v: Alignment => match v {
+        Alignment::LEFT => Self::Left,
+        Alignment::RIGHT => Self::Right,
+        _ => bail!("must be `left` or `right`"),
+    },
+}
@@ -46,6 +46,10 @@ pub struct ParElem {
     /// default, these two properties are up to the font, but they can also be
     /// configured manually with a text set rule.
     ///
+    /// The edges of a line are determined by the largest text in it. A line
+    /// containing larger text thus extends further, but the gap to its
+    /// neighbours stays the same.
+    ///
     /// By setting top edge, bottom edge, and leading, you can also configure a
     /// consistent baseline-to-baseline distance. You could, for instance, set
     /// the leading to `{1em}`, the top-edge to `{0.8em}`, and the bottom-edge
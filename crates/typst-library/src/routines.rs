@@ -21,6 +21,7 @@ use crate::layout::{
 };
 use crate::math::EquationElem;
 use crate::model::{DocumentInfo, EnumElem, ListElem, TableElem};
+use crate::text::RubyElem;
 use crate::visualize::{
     CircleElem, CurveElem, EllipseElem, ImageElem, LineElem, PathElem, PolygonElem,
     RectElem, SquareElem,
@@ -56,6 +57,7 @@ routines! {
     fn eval_string(
         routines: &Routines,
         world: Tracked<dyn World + '_>,
+        route: Tracked<Route>,
         string: &str,
         span: Span,
         mode: EvalMode,
@@ -322,6 +324,15 @@ routines! {
         region: Size,
     ) -> SourceResult<Vec<InlineItem>>
 
+    /// Lays out a [`RubyElem`] in a paragraph.
+    fn layout_ruby(
+        elem: &Packed<RubyElem>,
+        engine: &mut Engine,
+        locator: Locator,
+        styles: StyleChain,
+        region: Size,
+    ) -> SourceResult<Vec<InlineItem>>
+
     /// Lays out an [`EquationElem`] in a flow.
     fn layout_equation_block(
         elem: &Packed<EquationElem>,
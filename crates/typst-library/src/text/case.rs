@@ -1,5 +1,8 @@
-use crate::foundations::{cast, func, Cast, Content, Str};
-use crate::text::TextElem;
+use ecow::EcoString;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::foundations::{cast, func, Cast, Content, Str, Styles};
+use crate::text::{Lang, TextElem};
 
 /// Converts a string or content to lowercase.
 ///
@@ -14,7 +17,7 @@ pub fn lower(
     /// The text to convert to lowercase.
     text: Caseable,
 ) -> Caseable {
-    case(text, Case::Lower)
+    case(text, TextCase::Lower)
 }
 
 /// Converts a string or content to uppercase.
@@ -30,13 +33,49 @@ pub fn upper(
     /// The text to convert to uppercase.
     text: Caseable,
 ) -> Caseable {
-    case(text, Case::Upper)
+    case(text, TextCase::Upper)
+}
+
+/// Converts a string or content to title case.
+///
+/// The first letter of each word is converted to uppercase, while the rest of
+/// the word is left unchanged.
+///
+/// # Example
+/// ```example
+/// #title("the lord of the rings") \
+/// #title(skip: ("of", "the"))[the lord of the rings]
+/// ```
+#[func(title = "Title Case")]
+pub fn title(
+    /// Words that should not be capitalized, unless they are the first word
+    /// of the text. Comparison is case-insensitive.
+    #[named]
+    #[default]
+    skip: Vec<EcoString>,
+    /// The text to convert to title case.
+    text: Caseable,
+) -> Caseable {
+    match text {
+        Caseable::Str(v) => {
+            let context = TitleContext { skip: &skip, ..Default::default() };
+            Caseable::Str(TextCase::Title.apply_in(&v, Lang::ENGLISH, context).into())
+        }
+        Caseable::Content(v) => {
+            let mut styles = Styles::new();
+            styles.set(TextElem::set_case(Some(TextCase::Title)));
+            styles.set(TextElem::set_case_skip(skip));
+            Caseable::Content(v.styled_with_map(styles))
+        }
+    }
 }
 
 /// Change the case of text.
-fn case(text: Caseable, case: Case) -> Caseable {
+fn case(text: Caseable, case: TextCase) -> Caseable {
     match text {
-        Caseable::Str(v) => Caseable::Str(case.apply(&v).into()),
+        Caseable::Str(v) => Caseable::Str(
+            case.apply_in(&v, Lang::ENGLISH, TitleContext::default()).into(),
+        ),
         Caseable::Content(v) => {
             Caseable::Content(v.styled(TextElem::set_case(Some(case))))
         }
@@ -71,9 +110,89 @@ pub enum Case {
 impl Case {
     /// Apply the case to a string.
     pub fn apply(self, text: &str) -> String {
+        self.apply_in(text, Lang::ENGLISH)
+    }
+
+    /// Apply the case to a string in the given language.
+    pub fn apply_in(self, text: &str, lang: Lang) -> String {
+        // Turkish and Azerbaijani distinguish a dotted and a dotless i in both
+        // cases.
+        let dotted = matches!(lang.as_str(), "tr" | "az");
         match self {
+            Self::Lower if dotted => {
+                text.replace('I', "ı").replace('İ', "i").to_lowercase()
+            }
+            Self::Upper if dotted => text.replace('i', "İ").to_uppercase(),
             Self::Lower => text.to_lowercase(),
             Self::Upper => text.to_uppercase(),
         }
     }
 }
+
+/// A case transformation that the `lower`, `upper`, and `title` functions
+/// apply to content.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum TextCase {
+    /// Everything is lowercased.
+    Lower,
+    /// Everything is uppercased.
+    Upper,
+    /// The first letter of each word is uppercased.
+    Title,
+}
+
+impl TextCase {
+    /// Apply the case to a string in the given language.
+    ///
+    /// The `context` describes the text preceding `text` and is only relevant
+    /// for title case.
+    pub fn apply_in(self, text: &str, lang: Lang, context: TitleContext) -> String {
+        let TitleContext { mut in_word, seen_word, skip } = context;
+        match self {
+            Self::Lower => Case::Lower.apply_in(text, lang),
+            Self::Upper => Case::Upper.apply_in(text, lang),
+            Self::Title => {
+                let mut first = !seen_word;
+                let mut out = String::with_capacity(text.len());
+                for word in text.split_word_bounds() {
+                    let starts_word =
+                        word.chars().next().is_some_and(char::is_alphanumeric);
+                    let skipped = !first
+                        && skip.iter().any(|s| s.to_lowercase() == word.to_lowercase());
+                    if starts_word && !in_word && !skipped {
+                        let mut chars = word.chars();
+                        let head: String = chars.next().into_iter().collect();
+                        out.push_str(&Case::Upper.apply_in(&head, lang));
+                        out.push_str(chars.as_str());
+                    } else {
+                        out.push_str(word);
+                    }
+
+                    if starts_word {
+                        first = false;
+                    }
+                    in_word = word.chars().last().is_some_and(char::is_alphanumeric);
+                }
+                out
+            }
+        }
+    }
+}
+
+/// The text preceding a piece of text that is converted to title case.
+///
+/// A title can be split across several pieces of text, for instance across
+/// the text elements of a paragraph. This tells the conversion of each piece
+/// where it continues.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct TitleContext<'a> {
+    /// Whether the preceding text ends in the middle of a word, in which case
+    /// the first letter of the text is not capitalized.
+    pub in_word: bool,
+    /// Whether the preceding part of the title already contains a word. Words
+    /// in `skip` are capitalized anyway as long as this is `false`.
+    pub seen_word: bool,
+    /// Words that are not capitalized, unless they are the first word of the
+    /// title. Comparison is case-insensitive.
+    pub skip: &'a [EcoString],
+}
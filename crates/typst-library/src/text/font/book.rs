@@ -16,12 +16,18 @@ pub struct FontBook {
     families: BTreeMap<String, Vec<usize>>,
     /// Metadata about each font in the collection.
     infos: Vec<FontInfo>,
+    /// Maps from lowercased alias names to lowercased family names.
+    aliases: BTreeMap<String, String>,
 }
 
 impl FontBook {
     /// Create a new, empty font book.
     pub fn new() -> Self {
-        Self { families: BTreeMap::new(), infos: vec![] }
+        Self {
+            families: BTreeMap::new(),
+            infos: vec![],
+            aliases: BTreeMap::new(),
+        }
     }
 
     /// Create a font book from a collection of font infos.
@@ -51,9 +57,32 @@ impl FontBook {
         self.infos.get(index)
     }
 
-    /// Returns true if the book contains a font family with the given name.
+    /// Define an alias that resolves to the first of the given `families`
+    /// contained in the book.
+    ///
+    /// This way, documents can refer to a logical family name that is mapped
+    /// to a concrete font family by the host. An alias takes precedence over a
+    /// font family with the same name.
+    ///
+    /// Returns `false` and leaves the alias undefined if none of the families
+    /// is contained in the book.
+    pub fn alias(&mut self, alias: &str, families: &[&str]) -> bool {
+        let Some(family) = families
+            .iter()
+            .map(|family| family.to_lowercase())
+            .find(|family| self.families.contains_key(family))
+        else {
+            return false;
+        };
+
+        self.aliases.insert(alias.to_lowercase(), family);
+        true
+    }
+
+    /// Returns true if the book contains a font family or alias with the given
+    /// name.
     pub fn contains_family(&self, family: &str) -> bool {
-        self.families.contains_key(family)
+        self.families.contains_key(self.resolve_alias(family))
     }
 
     /// An ordered iterator over all font families this book knows and details
@@ -70,19 +99,34 @@ impl FontBook {
         })
     }
 
+    /// Whether any font in the book covers the given character.
+    pub fn covers(&self, c: char) -> bool {
+        self.covering(c).next().is_some()
+    }
+
+    /// An iterator over the indices of all fonts that cover the given
+    /// character.
+    pub fn covering(&self, c: char) -> impl Iterator<Item = usize> + '_ {
+        self.infos
+            .iter()
+            .enumerate()
+            .filter(move |(_, info)| info.coverage.contains(c as u32))
+            .map(|(index, _)| index)
+    }
+
     /// Try to find a font from the given `family` that matches the given
     /// `variant` as closely as possible.
     ///
     /// The `family` should be all lowercase.
     pub fn select(&self, family: &str, variant: FontVariant) -> Option<usize> {
-        let ids = self.families.get(family)?;
+        let ids = self.families.get(self.resolve_alias(family))?;
         self.find_best_variant(None, variant, ids.iter().copied())
     }
 
     /// Iterate over all variants of a family.
     pub fn select_family(&self, family: &str) -> impl Iterator<Item = usize> + '_ {
         self.families
-            .get(family)
+            .get(self.resolve_alias(family))
             .map(|vec| vec.as_slice())
             .unwrap_or_default()
             .iter()
@@ -101,17 +145,17 @@ impl FontBook {
     ) -> Option<usize> {
         // Find the fonts that contain the text's first non-space char ...
         let c = text.chars().find(|c| !c.is_whitespace())?;
-        let ids = self
-            .infos
-            .iter()
-            .enumerate()
-            .filter(|(_, info)| info.coverage.contains(c as u32))
-            .map(|(index, _)| index);
+        let ids = self.covering(c);
 
         // ... and find the best variant among them.
         self.find_best_variant(like, variant, ids)
     }
 
+    /// Resolve a lowercased family name through the defined aliases.
+    fn resolve_alias<'a>(&'a self, family: &'a str) -> &'a str {
+        self.aliases.get(family).map_or(family, String::as_str)
+    }
+
     /// Find the font in the passed iterator that
     /// - is closest to the font `like` (if any)
     /// - is closest to the given `variant`
@@ -512,6 +556,48 @@ mod tests {
         assert_eq!(typographic_family("Font Ultra Bold"), "Font");
     }
 
+    #[test]
+    fn test_book_families_and_coverage() {
+        let info = |family: &str, codepoints: &[u32]| FontInfo {
+            family: family.into(),
+            variant: FontVariant::default(),
+            flags: FontFlags::empty(),
+            coverage: Coverage::from_vec(codepoints.to_vec()),
+        };
+
+        let book = FontBook::from_infos([
+            info("Beta", &['b' as u32]),
+            info("Alpha", &['a' as u32]),
+            info("beta", &['c' as u32]),
+        ]);
+
+        let families: Vec<_> = book.families().map(|(family, _)| family).collect();
+        assert_eq!(families, ["Alpha", "Beta"]);
+        assert!(book.covers('a'));
+        assert!(book.covers('c'));
+        assert!(!book.covers('d'));
+        assert_eq!(book.covering('b').collect::<Vec<_>>(), [0]);
+    }
+
+    #[test]
+    fn test_book_alias() {
+        let info = |family: &str| FontInfo {
+            family: family.into(),
+            variant: FontVariant::default(),
+            flags: FontFlags::empty(),
+            coverage: Coverage::from_vec(vec![]),
+        };
+
+        let mut book = FontBook::from_infos([info("Alpha"), info("Beta")]);
+        assert!(book.alias("Body", &["Missing", "Beta", "Alpha"]));
+        assert!(!book.alias("Code", &["Missing"]));
+        assert!(book.contains_family("body"));
+        assert!(!book.contains_family("code"));
+        assert_eq!(book.select("body", FontVariant::default()), Some(1));
+        assert_eq!(book.select_family("body").collect::<Vec<_>>(), [1]);
+        assert_eq!(book.families().count(), 2);
+    }
+
     #[test]
     fn test_coverage() {
         #[track_caller]
@@ -14,12 +14,14 @@ use std::fmt::{self, Debug, Formatter};
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
+use rustybuzz::{BufferFlags, Feature, UnicodeBuffer};
 use ttf_parser::GlyphId;
+use typst_syntax::Span;
 
 use self::book::find_name;
 use crate::foundations::{Bytes, Cast};
-use crate::layout::{Abs, Em, Frame};
-use crate::text::{BottomEdge, TopEdge};
+use crate::layout::{Abs, Dir, Em, Frame};
+use crate::text::{BottomEdge, Glyph, TopEdge};
 
 /// An OpenType font.
 ///
@@ -173,6 +175,75 @@ impl Font {
 
         (top, bottom)
     }
+
+    /// Shape a run of text with this font, without going through layout.
+    ///
+    /// This is useful for measuring strings: The width of the text at a given
+    /// font size is the sum of the glyphs' advances, as computed by
+    /// [`text_width`](Self::text_width).
+    ///
+    /// The whole text is shaped as a single run in the given horizontal
+    /// direction, with the script and language guessed from the text. There is
+    /// no bidirectional reordering, font fallback, line breaking, hyphenation,
+    /// or justification. Characters that the font does not cover produce
+    /// glyphs with ID zero. The glyphs are returned in visual order and their
+    /// spans are detached.
+    pub fn shape(&self, text: &str, dir: Dir, features: &[Feature]) -> Vec<Glyph> {
+        let mut buffer = UnicodeBuffer::new();
+        buffer.push_str(text);
+        buffer.set_direction(match dir {
+            Dir::RTL => rustybuzz::Direction::RightToLeft,
+            _ => rustybuzz::Direction::LeftToRight,
+        });
+        buffer.guess_segment_properties();
+        buffer.set_flags(BufferFlags::REMOVE_DEFAULT_IGNORABLES);
+
+        let buffer = rustybuzz::shape(self.rusty(), features, buffer);
+        let infos = buffer.glyph_infos();
+        let positions = buffer.glyph_positions();
+
+        // A glyph covers the text from its cluster up to the next cluster.
+        let mut clusters: Vec<usize> =
+            infos.iter().map(|info| info.cluster as usize).collect();
+        clusters.sort_unstable();
+        clusters.dedup();
+
+        let to_u16 = |offset: usize| u16::try_from(offset).unwrap_or(u16::MAX);
+        infos
+            .iter()
+            .zip(positions)
+            .map(|(info, pos)| {
+                let start = info.cluster as usize;
+                let next = clusters.partition_point(|&cluster| cluster <= start);
+                let end = clusters.get(next).copied().unwrap_or(text.len());
+                Glyph {
+                    id: info.glyph_id as u16,
+                    x_advance: self.to_em(pos.x_advance),
+                    x_offset: self.to_em(pos.x_offset),
+                    range: to_u16(start)..to_u16(end),
+                    span: (Span::detached(), 0),
+                }
+            })
+            .collect()
+    }
+
+    /// Shape a run of text with this font and return its width at the given
+    /// font size.
+    ///
+    /// The text is shaped as described for [`shape`](Self::shape).
+    pub fn text_width(
+        &self,
+        text: &str,
+        dir: Dir,
+        features: &[Feature],
+        size: Abs,
+    ) -> Abs {
+        self.shape(text, dir, features)
+            .iter()
+            .map(|glyph| glyph.x_advance)
+            .sum::<Em>()
+            .at(size)
+    }
 }
 
 impl Hash for Font {
@@ -308,3 +379,60 @@ pub enum TextEdgeBounds<'a> {
     /// Use the dimension of the given frame for the bounds.
     Frame(&'a Frame),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A test font that covers basic Latin.
+    fn latin_font() -> Font {
+        typst_dev_assets::fonts()
+            .flat_map(|data| Font::iter(Bytes::from_static(data)))
+            .find(|font| font.ttf().glyph_index('a').is_some())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_font_shape() {
+        let font = latin_font();
+        let glyphs = font.shape("ab", Dir::LTR, &[]);
+        assert_eq!(glyphs.len(), 2);
+        assert_eq!(glyphs[0].range(), 0..1);
+        assert_eq!(glyphs[1].range(), 1..2);
+        for glyph in &glyphs {
+            assert_eq!(Some(glyph.x_advance), font.advance(glyph.id));
+        }
+
+        // Right-to-left text is returned in visual order.
+        let glyphs = font.shape("ab", Dir::RTL, &[]);
+        assert_eq!(glyphs[0].range(), 1..2);
+        assert_eq!(glyphs[1].range(), 0..1);
+    }
+
+    #[test]
+    fn test_font_shape_deterministic() {
+        let font = latin_font();
+        for dir in [Dir::LTR, Dir::RTL] {
+            let text = "Office fluff, shaped twice!";
+            let glyphs = font.shape(text, dir, &[]);
+            assert_eq!(glyphs, font.shape(text, dir, &[]));
+
+            // The glyphs are in visual order.
+            let mut starts: Vec<_> = glyphs.iter().map(|g| g.range.start).collect();
+            if dir == Dir::RTL {
+                starts.reverse();
+            }
+            assert!(starts.windows(2).all(|pair| pair[0] <= pair[1]));
+        }
+    }
+
+    #[test]
+    fn test_font_text_width() {
+        let font = latin_font();
+        let size = Abs::pt(10.0);
+        let advance = |c| font.advance(font.ttf().glyph_index(c).unwrap().0).unwrap();
+        let expected = (advance('a') + advance('b')).at(size);
+        assert_eq!(font.text_width("ab", Dir::LTR, &[], size), expected);
+        assert_eq!(font.text_width("", Dir::LTR, &[], size), Abs::zero());
+    }
+}
@@ -0,0 +1,162 @@
+use ecow::{eco_format, EcoString};
+use ttf_parser::Tag;
+
+use super::shift::is_shapable;
+use crate::diag::SourceResult;
+use crate::engine::Engine;
+use crate::foundations::{elem, Content, Packed, SequenceElem, Show, StyleChain};
+use crate::layout::{Em, Length};
+use crate::text::{variant, SpaceElem, TextElem, TextSize};
+use crate::World;
+
+/// The fraction slash (U+2044) used between numerator and denominator.
+const FRACTION_SLASH: char = '\u{2044}';
+
+/// A slashed fraction in running text.
+///
+/// Unlike [`math.frac`]($math.frac), which stacks the numerator above the
+/// denominator, this renders a fraction like `1/2` with a small numerator and
+/// denominator so that it fits into a line of text. This is useful in
+/// recipes, measurements, and other casual text.
+///
+/// # Example
+/// ```example
+/// Add #textfrac[1][2] cup of sugar
+/// and #textfrac[3][16] teaspoon of salt.
+///
+/// #textfrac(typographic: false)[1][2]
+/// ```
+#[elem(name = "textfrac", title = "Text Fraction", Show)]
+pub struct TextFracElem {
+    /// Whether to prefer the font's own fraction glyphs.
+    ///
+    /// If this is enabled, Typst first tries to use a precomposed fraction
+    /// character like `½`. If the font doesn't have one, it uses the font's
+    /// OpenType `frac` feature. If that fails, too, or the numerator or
+    /// denominator aren't plain text, it falls back to synthesizing the
+    /// fraction from a shrunk and raised numerator, a fraction slash, and a
+    /// shrunk denominator.
+    #[default(true)]
+    pub typographic: bool,
+
+    /// The baseline shift of the numerator in synthetic fractions. Does not
+    /// apply if `typographic` is true and the font can render the fraction
+    /// itself.
+    #[default(Em::new(-0.35).into())]
+    pub baseline: Length,
+
+    /// The font size of the numerator and denominator in synthetic fractions.
+    /// Does not apply if `typographic` is true and the font can render the
+    /// fraction itself.
+    #[default(TextSize(Em::new(0.6).into()))]
+    pub size: TextSize,
+
+    /// The numerator of the fraction.
+    #[required]
+    pub num: Content,
+
+    /// The denominator of the fraction.
+    #[required]
+    pub denom: Content,
+}
+
+impl Show for Packed<TextFracElem> {
+    #[typst_macros::time(name = "textfrac", span = self.span())]
+    fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let num = self.num();
+        let denom = self.denom();
+
+        if self.typographic(styles) {
+            if let (Some(num), Some(denom)) = (plain_text(num), plain_text(denom)) {
+                if let Some(c) = precomposed(&num, &denom) {
+                    let text = EcoString::from(c);
+                    if is_shapable(engine, &text, styles) {
+                        return Ok(TextElem::packed(text));
+                    }
+                }
+
+                if has_frac_feature(engine, styles) {
+                    let text = eco_format!("{num}{FRACTION_SLASH}{denom}");
+                    return Ok(
+                        TextElem::packed(text).styled(TextElem::set_fractions(true))
+                    );
+                }
+            }
+        }
+
+        let slash = if is_shapable(engine, &EcoString::from(FRACTION_SLASH), styles) {
+            FRACTION_SLASH
+        } else {
+            '/'
+        };
+
+        Ok(Content::sequence([
+            num.clone()
+                .styled(TextElem::set_baseline(self.baseline(styles)))
+                .styled(TextElem::set_size(self.size(styles))),
+            TextElem::packed(EcoString::from(slash)),
+            denom.clone().styled(TextElem::set_size(self.size(styles))),
+        ]))
+    }
+}
+
+/// Extracts the text of `content` if it only consists of `Text`, `Space`, and
+/// `Empty` leaves.
+fn plain_text(content: &Content) -> Option<EcoString> {
+    if content.is::<SpaceElem>() {
+        Some(' '.into())
+    } else if let Some(elem) = content.to_packed::<TextElem>() {
+        Some(elem.text().clone())
+    } else if let Some(sequence) = content.to_packed::<SequenceElem>() {
+        let mut text = EcoString::new();
+        for child in &sequence.children {
+            text.push_str(&plain_text(child)?);
+        }
+        Some(text)
+    } else {
+        None
+    }
+}
+
+/// Checks whether the first retrievable family supports the OpenType `frac`
+/// feature.
+fn has_frac_feature(engine: &Engine, styles: StyleChain) -> bool {
+    let world = engine.world;
+    TextElem::font_in(styles)
+        .into_iter()
+        .find_map(|family| {
+            world
+                .book()
+                .select(family.as_str(), variant(styles))
+                .and_then(|id| world.font(id))
+        })
+        .and_then(|font| font.ttf().tables().gsub)
+        .is_some_and(|gsub| gsub.features.find(Tag::from_bytes(b"frac")).is_some())
+}
+
+/// Find the precomposed Unicode vulgar fraction for a numerator and
+/// denominator.
+fn precomposed(num: &str, denom: &str) -> Option<char> {
+    Some(match (num.trim(), denom.trim()) {
+        ("1", "2") => '½',
+        ("1", "3") => '⅓',
+        ("2", "3") => '⅔',
+        ("1", "4") => '¼',
+        ("3", "4") => '¾',
+        ("1", "5") => '⅕',
+        ("2", "5") => '⅖',
+        ("3", "5") => '⅗',
+        ("4", "5") => '⅘',
+        ("1", "6") => '⅙',
+        ("5", "6") => '⅚',
+        ("1", "7") => '⅐',
+        ("1", "8") => '⅛',
+        ("3", "8") => '⅜',
+        ("5", "8") => '⅝',
+        ("7", "8") => '⅞',
+        ("1", "9") => '⅑',
+        ("1", "10") => '⅒',
+        ("0", "3") => '↉',
+        _ => return None,
+    })
+}
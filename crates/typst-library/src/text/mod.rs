@@ -3,30 +3,36 @@
 mod case;
 mod deco;
 mod font;
+mod frac;
 mod item;
 mod lang;
 mod linebreak;
 #[path = "lorem.rs"]
 mod lorem_;
 mod raw;
+mod ruby;
 mod shift;
 #[path = "smallcaps.rs"]
 mod smallcaps_;
 mod smartquote;
 mod space;
+mod unit;
 
 pub use self::case::*;
 pub use self::deco::*;
 pub use self::font::*;
+pub use self::frac::*;
 pub use self::item::*;
 pub use self::lang::*;
 pub use self::linebreak::*;
 pub use self::lorem_::*;
 pub use self::raw::*;
+pub use self::ruby::*;
 pub use self::shift::*;
 pub use self::smallcaps_::*;
 pub use self::smartquote::*;
 pub use self::space::*;
+pub use self::unit::*;
 
 use std::fmt::{self, Debug, Formatter};
 use std::hash::Hash;
@@ -69,14 +75,18 @@ pub(super) fn define(global: &mut Scope) {
     global.define_elem::<SmartQuoteElem>();
     global.define_elem::<SubElem>();
     global.define_elem::<SuperElem>();
+    global.define_elem::<TextFracElem>();
+    global.define_elem::<UnitElem>();
     global.define_elem::<UnderlineElem>();
     global.define_elem::<OverlineElem>();
     global.define_elem::<StrikeElem>();
     global.define_elem::<HighlightElem>();
     global.define_elem::<SmallcapsElem>();
     global.define_elem::<RawElem>();
+    global.define_elem::<RubyElem>();
     global.define_func::<lower>();
     global.define_func::<upper>();
+    global.define_func::<title>();
     global.define_func::<lorem>();
 }
 
@@ -178,11 +188,10 @@ pub struct TextElem {
     /// contains no match. This lets Typst search through all available fonts
     /// for the most similar one that has the necessary glyphs.
     ///
-    /// _Note:_ Currently, there are no warnings when fallback is disabled and
-    /// no glyphs are found. Instead, your text shows up in the form of "tofus":
-    /// Small boxes that indicate the lack of an appropriate glyph. In the
-    /// future, you will be able to instruct Typst to issue warnings so you know
-    /// something is up.
+    /// When fallback is disabled and no glyphs are found, your text shows up
+    /// in the form of "tofus": Small boxes that indicate the lack of an
+    /// appropriate glyph. Use the [`missing`]($text.missing) setting to be
+    /// warned about this instead.
     ///
     /// ```example
     /// #set text(font: "Inria Serif")
@@ -195,6 +204,26 @@ pub struct TextElem {
     #[ghost]
     pub fallback: bool,
 
+    /// How to handle characters that no available font has a glyph for.
+    ///
+    /// By default, such characters are silently displayed with the font's
+    /// placeholder glyph, typically a small box ("tofu"). To catch coverage
+    /// problems, you can instead ask Typst to report them as a warning or to
+    /// fail compilation with an error. The report includes the affected text
+    /// and points to where it occurs.
+    ///
+    /// In all modes, the missing characters are displayed with the font's
+    /// placeholder glyph. Showing a visible placeholder with the codepoint of
+    /// each missing character instead is not supported.
+    ///
+    /// ```example
+    /// #set text(font: "Inria Serif", fallback: false, missing: "warn")
+    /// هذا عربي
+    /// ```
+    #[default(MissingGlyphs::Tofu)]
+    #[ghost]
+    pub missing: MissingGlyphs,
+
     /// The desired font style.
     ///
     /// When an italic style is requested and only an oblique one is available,
@@ -513,6 +542,28 @@ pub struct TextElem {
     #[ghost]
     pub hyphenate: Hyphenate,
 
+    /// Words with manually specified hyphenation points.
+    ///
+    /// Each entry is a word with hyphens at the positions where it may be
+    /// hyphenated. Matching words are hyphenated only at these positions
+    /// instead of according to the language's patterns, and an entry without
+    /// any hyphens keeps its word from being hyphenated at all. Words are
+    /// matched case-insensitively. The exceptions only take effect where
+    /// [hyphenation]($text.hyphenate) is enabled.
+    ///
+    /// ```example
+    /// #set page(width: 60pt)
+    /// #set text(
+    ///   hyphenate: true,
+    ///   hyphenation-exceptions: ("data-base", "Typst"),
+    /// )
+    /// A fast database
+    /// written in Typst.
+    /// ```
+    #[borrowed]
+    #[ghost]
+    pub hyphenation_exceptions: Vec<EcoString>,
+
     /// The "cost" of various choices when laying out text. A higher cost means
     /// the layout engine will make the choice less often. Costs are specified
     /// as a ratio of the default cost, so `{50%}` will make text layout twice
@@ -689,7 +740,9 @@ pub struct TextElem {
     ///
     /// It is not advisable to enable this property globally as it will mess
     /// with all appearances of numbers after a slash (e.g., in URLs). Instead,
-    /// enable it locally when you want a fraction.
+    /// enable it locally when you want a fraction. The [`textfrac`]($textfrac)
+    /// function does this for you and falls back to a synthesized fraction if
+    /// the font doesn't support the feature.
     ///
     /// ```example
     /// 1/2 \
@@ -752,7 +805,13 @@ pub struct TextElem {
     /// A case transformation that should be applied to the text.
     #[internal]
     #[ghost]
-    pub case: Option<Case>,
+    pub case: Option<TextCase>,
+
+    /// Words that are exempt from a title case transformation.
+    #[internal]
+    #[borrowed]
+    #[ghost]
+    pub case_skip: Vec<EcoString>,
 
     /// Whether small capital glyphs should be used. ("smcp")
     #[internal]
@@ -1188,6 +1247,17 @@ pub enum NumberType {
     OldStyle,
 }
 
+/// How to handle characters that no available font has a glyph for.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum MissingGlyphs {
+    /// Display the font's placeholder glyph without reporting anything.
+    Tofu,
+    /// Display the placeholder glyph and emit a warning.
+    Warn,
+    /// Fail with an error.
+    Error,
+}
+
 /// The width of numbers / figures.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
 pub enum NumberWidth {
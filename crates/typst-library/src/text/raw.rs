@@ -6,7 +6,7 @@ use std::sync::{Arc, LazyLock};
 use ecow::{eco_format, EcoString, EcoVec};
 use syntect::highlighting::{self as synt, Theme};
 use syntect::parsing::{SyntaxDefinition, SyntaxSet, SyntaxSetBuilder};
-use typst_syntax::{split_newlines, LinkedNode, Span, Spanned};
+use typst_syntax::{is_newline, split_newlines, LinkedNode, Span, Spanned};
 use unicode_segmentation::UnicodeSegmentation;
 
 use super::Lang;
@@ -190,6 +190,12 @@ pub struct RawElem {
     /// definitions should be in the
     /// [`sublime-syntax` file format](https://www.sublimetext.com/docs/syntax.html).
     ///
+    /// A definition is used for all languages listed in its `file_extensions`.
+    /// Since the paths are resolved where the set rule is written, packages
+    /// can ship their own definitions and apply them in a template function.
+    /// Raw text in a language that neither the built-in nor the additional
+    /// definitions know is shown without highlighting.
+    ///
     /// ````example
     /// #set raw(syntaxes: "SExpressions.sublime-syntax")
     ///
@@ -254,7 +260,8 @@ pub struct RawElem {
     pub theme_data: Option<Bytes>,
 
     /// The size for a tab stop in spaces. A tab is replaced with enough spaces to
-    /// align with the next multiple of the size.
+    /// align with the next multiple of the size, counted from the start of
+    /// the line.
     ///
     /// ````example
     /// #set raw(tab-size: 8)
@@ -919,7 +926,7 @@ fn align_tabs(text: &str, tab_size: usize) -> EcoString {
                 res.push_str(&replacement[..required]);
                 column += required;
             }
-            "\n" => {
+            _ if grapheme.chars().any(is_newline) => {
                 res.push_str(grapheme);
                 column = 0;
             }
@@ -0,0 +1,81 @@
+use crate::diag::SourceResult;
+use crate::engine::Engine;
+use crate::foundations::{elem, Cast, Content, NativeElement, Packed, Show, StyleChain};
+use crate::layout::{Em, InlineElem, Length};
+use crate::text::TextSize;
+
+/// Annotates text with small ruby characters.
+///
+/// Ruby annotations are typically used in East Asian typesetting to indicate
+/// the pronunciation of characters, for instance with Japanese _furigana._ The
+/// annotation is set in a smaller font size and centered above the base text.
+/// If the annotation is wider than the base text, the base text is centered
+/// below it.
+///
+/// # Example
+/// ```example
+/// #ruby[東京][とうきょう]は日本の首都です。
+///
+/// #ruby(mode: "mono")[漢字][かん じ]を学ぶ。
+/// ```
+#[elem(Show)]
+pub struct RubyElem {
+    /// The font size of the annotation, relative to the surrounding text.
+    ///
+    /// ```example
+    /// #ruby(size: 0.4em)[読][よ]む
+    /// ```
+    #[default(TextSize(Em::new(0.5).into()))]
+    pub size: TextSize,
+
+    /// The spacing between the base text and the annotation.
+    #[resolve]
+    #[default(Em::new(0.1).into())]
+    pub gap: Length,
+
+    /// How the annotation is distributed over the base text.
+    ///
+    /// In `{"mono"}` mode, the annotation must consist of space-separated
+    /// parts, one for each character of the base text. Each character then
+    /// receives its own part of the annotation and a line may break between
+    /// the characters. Whitespace in the base text is dropped and does not
+    /// need an annotation part.
+    ///
+    /// Both the base text and the annotation are treated as plain text in
+    /// this mode, so styling applied _within_ them (like `[*漢*字]`) is lost
+    /// and Typst warns about it. To style the characters, apply the styling
+    /// to the whole ruby instead.
+    ///
+    /// ```example
+    /// #set text(fill: eastern)
+    /// #ruby(mode: "mono")[漢字][かん じ]
+    /// ```
+    #[default(RubyMode::Group)]
+    pub mode: RubyMode,
+
+    /// The base text to annotate.
+    #[required]
+    pub base: Content,
+
+    /// The annotation to show above the base text.
+    #[required]
+    pub annotation: Content,
+}
+
+impl Show for Packed<RubyElem> {
+    fn show(&self, engine: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(InlineElem::layouter(self.clone(), engine.routines.layout_ruby)
+            .pack()
+            .spanned(self.span()))
+    }
+}
+
+/// How a ruby annotation is distributed over its base text.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum RubyMode {
+    /// The whole annotation is centered over the whole base text.
+    #[default]
+    Group,
+    /// Each character of the base text is annotated separately.
+    Mono,
+}
@@ -149,7 +149,7 @@ fn convert_script(content: &Content, sub: bool) -> Option<EcoString> {
 
 /// Checks whether the first retrievable family contains all code points of the
 /// given string.
-fn is_shapable(engine: &Engine, text: &str, styles: StyleChain) -> bool {
+pub(super) fn is_shapable(engine: &Engine, text: &str, styles: StyleChain) -> bool {
     let world = engine.world;
     for family in TextElem::font_in(styles) {
         if let Some(font) = world
@@ -386,3 +386,62 @@ cast! {
         single: Smart::Auto,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[track_caller]
+    fn test(lang: Lang, region: Option<&str>, expected: [&str; 4]) {
+        let region = region.map(|r| Region::from_str(r).unwrap());
+        let quotes = SmartQuotes::get(&Smart::Auto, lang, region, false);
+        assert_eq!(
+            [
+                quotes.single_open,
+                quotes.single_close,
+                quotes.double_open,
+                quotes.double_close,
+            ],
+            expected,
+        );
+    }
+
+    #[test]
+    fn test_smart_quotes_by_language() {
+        test(Lang::ENGLISH, None, ["‘", "’", "“", "”"]);
+        test(Lang::GERMAN, None, ["‚", "‘", "„", "“"]);
+        test(Lang::GERMAN, Some("CH"), ["‹", "›", "«", "»"]);
+        test(Lang::FRENCH, None, ["‹\u{A0}", "\u{A0}›", "«\u{A0}", "\u{A0}»"]);
+        test(
+            Lang::FRENCH,
+            Some("CH"),
+            ["‹\u{202F}", "\u{202F}›", "«\u{202F}", "\u{202F}»"],
+        );
+        test(Lang::from_str("xx").unwrap(), None, ["‘", "’", "“", "”"]);
+    }
+
+    #[test]
+    fn test_smart_quotes_custom() {
+        let custom = Smart::Custom(SmartQuoteDict {
+            double: Smart::Custom(SmartQuoteSet {
+                open: "»".into(), close: "«".into()
+            }),
+            single: Smart::Auto,
+        });
+        let quotes = SmartQuotes::get(&custom, Lang::GERMAN, None, false);
+        assert_eq!([quotes.double_open, quotes.double_close], ["»", "«"]);
+        assert_eq!([quotes.single_open, quotes.single_close], ["‚", "‘"]);
+    }
+
+    #[test]
+    fn test_smart_quoter_nesting() {
+        let quotes = SmartQuotes::get(&Smart::Auto, Lang::GERMAN, None, false);
+        let mut quoter = SmartQuoter::new();
+        assert_eq!(quoter.quote(None, &quotes, true), "„");
+        assert_eq!(quoter.quote(Some(' '), &quotes, false), "‚");
+        assert_eq!(quoter.quote(Some('a'), &quotes, false), "‘");
+        assert_eq!(quoter.quote(Some('a'), &quotes, true), "“");
+    }
+}
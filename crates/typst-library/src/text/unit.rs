@@ -0,0 +1,46 @@
+use crate::diag::SourceResult;
+use crate::engine::Engine;
+use crate::foundations::{elem, Content, NativeElement, Packed, Show, StyleChain};
+use crate::layout::BoxElem;
+use crate::text::TextElem;
+
+/// The narrow no-break space (U+202F) placed between value and unit.
+const NARROW_NBSP: &str = "\u{202F}";
+
+/// A physical quantity consisting of a value and a unit.
+///
+/// The value and the unit are separated by a narrow no-break space, as
+/// recommended by typographic conventions for quantities. The line is never
+/// broken between the value and the unit, nor within the unit itself, so
+/// that compound units like `km/h` stay together.
+///
+/// # Example
+/// ```example
+/// The car drove at #unit[120][km/h]
+/// for #unit[2.5][h] and covered a
+/// distance of #unit[300][km].
+/// ```
+#[elem(title = "Unit", Show)]
+pub struct UnitElem {
+    /// The numeric value of the quantity.
+    #[required]
+    pub value: Content,
+
+    /// The unit of the quantity.
+    #[required]
+    pub unit: Content,
+}
+
+impl Show for Packed<UnitElem> {
+    #[typst_macros::time(name = "unit", span = self.span())]
+    fn show(&self, _: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(Content::sequence([
+            self.value.clone(),
+            TextElem::packed(NARROW_NBSP),
+            BoxElem::new()
+                .with_body(Some(self.unit.clone()))
+                .pack()
+                .spanned(self.span()),
+        ]))
+    }
+}
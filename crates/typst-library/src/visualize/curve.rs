@@ -1,11 +1,14 @@
+use ecow::{eco_format, EcoString};
 use kurbo::ParamCurveExtrema;
 use typst_macros::{scope, Cast};
+use typst_syntax::Spanned;
 use typst_utils::Numeric;
 
 use crate::diag::{bail, HintedStrResult, HintedString, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, Content, NativeElement, Packed, Show, Smart, StyleChain,
+    cast, elem, func, Array, Content, IntoValue, NativeElement, Packed, Repr, Show,
+    Smart, Str, StyleChain,
 };
 use crate::layout::{Abs, Axes, BlockElem, Length, Point, Rel, Size};
 use crate::visualize::{FillRule, Paint, Stroke};
@@ -118,6 +121,68 @@ impl CurveElem {
 
     #[elem]
     type CurveClose;
+
+    /// Parses [SVG path data](https://www.w3.org/TR/SVG/paths.html#PathData)
+    /// into curve components.
+    ///
+    /// Coordinates are interpreted as points. Arcs are approximated with
+    /// cubic segments. The resulting components can be passed to a curve with
+    /// the spread operator.
+    ///
+    /// ```example
+    /// #curve(
+    ///   fill: eastern,
+    ///   ..curve.svg("M 10 0 L 20 20 L 0 20 Z"),
+    /// )
+    /// ```
+    #[func(title = "Curve from SVG")]
+    pub fn svg(
+        /// The path data, as it would appear in the `d` attribute of an SVG
+        /// `<path>` element.
+        data: Spanned<Str>,
+    ) -> SourceResult<Array> {
+        let path = match kurbo::BezPath::from_svg(&data.v) {
+            Ok(path) => path,
+            Err(err) => {
+                let message: EcoString = match err {
+                    kurbo::SvgParseError::Wrong => "expected a number".into(),
+                    kurbo::SvgParseError::UnexpectedEof => {
+                        "unexpected end of path data".into()
+                    }
+                    kurbo::SvgParseError::UnknownCommand(c) => {
+                        eco_format!("unknown command {}", c.repr())
+                    }
+                };
+                bail!(data.span, "failed to parse SVG path data ({message})")
+            }
+        };
+
+        let point = |p: kurbo::Point| {
+            Axes::new(Abs::pt(p.x), Abs::pt(p.y)).map(|v| Length::from(v).into())
+        };
+
+        Ok(path
+            .elements()
+            .iter()
+            .map(|&el| match el {
+                kurbo::PathEl::MoveTo(p) => CurveMove::new(point(p)).pack(),
+                kurbo::PathEl::LineTo(p) => CurveLine::new(point(p)).pack(),
+                kurbo::PathEl::QuadTo(c, p) => {
+                    CurveQuad::new(Smart::Custom(Some(point(c))), point(p)).pack()
+                }
+                kurbo::PathEl::CurveTo(c1, c2, p) => CurveCubic::new(
+                    Some(Smart::Custom(point(c1))),
+                    Some(point(c2)),
+                    point(p),
+                )
+                .pack(),
+                kurbo::PathEl::ClosePath => {
+                    CurveClose::new().with_mode(CloseMode::Straight).pack()
+                }
+            })
+            .map(IntoValue::into_value)
+            .collect())
+    }
 }
 
 /// A component used for curve creation.
@@ -6,6 +6,7 @@ mod svg;
 pub use self::raster::{RasterFormat, RasterImage};
 pub use self::svg::SvgImage;
 
+use std::ffi::OsStr;
 use std::fmt::{self, Debug, Formatter};
 use std::sync::Arc;
 
@@ -14,11 +15,11 @@ use ecow::EcoString;
 use typst_syntax::{Span, Spanned};
 use typst_utils::LazyHash;
 
-use crate::diag::{At, SourceResult, StrResult};
+use crate::diag::{bail, At, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, func, scope, Bytes, Cast, Content, NativeElement, Packed, Show, Smart,
-    StyleChain,
+    cast, dict, elem, func, scope, Bytes, Cast, Content, Dict, NativeElement, Packed,
+    Show, Smart, StyleChain,
 };
 use crate::layout::{BlockElem, Length, Rel, Sizing};
 use crate::loading::Readable;
@@ -156,6 +157,51 @@ impl ImageElem {
         }
         Ok(elem.pack().spanned(span))
     }
+
+    /// Retrieves information about an image file without displaying it.
+    ///
+    /// Returns a dictionary with the image's `width` and `height` in pixels,
+    /// its pixel density `dpi` in pixels per inch (or `{none}` if the file
+    /// doesn't specify one), and its `format`. This is useful to make layout
+    /// decisions based on an image's natural size or aspect ratio.
+    ///
+    /// Raster images are decoded just once, even if they are also displayed
+    /// with the [`image`] function.
+    ///
+    /// ```example
+    /// #let info = image.info("tiger.jpg")
+    /// The image is #info.width × #info.height
+    /// pixels large.
+    /// ```
+    #[func(title = "Image Info")]
+    pub fn info(
+        /// The engine.
+        engine: &mut Engine,
+        /// Path to an image file.
+        ///
+        /// For more details, see the [Paths section]($syntax/#paths).
+        path: Spanned<EcoString>,
+        /// The image's format. Detected automatically by default.
+        #[named]
+        #[default]
+        format: Smart<ImageFormat>,
+    ) -> SourceResult<Dict> {
+        let Spanned { v: path, span } = path;
+        let id = span.resolve_path(&path).at(span)?;
+        let data = Readable::Bytes(engine.world.file(id).at(span)?);
+        let format = match format {
+            Smart::Custom(v) => v,
+            Smart::Auto => ImageFormat::detect(&path, &data).at(span)?,
+        };
+
+        let image = Image::new(data.into(), format, None).at(span)?;
+        Ok(dict! {
+            "width" => image.width(),
+            "height" => image.height(),
+            "dpi" => image.dpi(),
+            "format" => format,
+        })
+    }
 }
 
 impl Show for Packed<ImageElem> {
@@ -337,6 +383,32 @@ pub enum ImageFormat {
     Vector(VectorFormat),
 }
 
+impl ImageFormat {
+    /// Determine the format of an image from its path's extension or, if that
+    /// fails, from its data.
+    pub fn detect(path: &str, data: &Readable) -> StrResult<Self> {
+        let ext = std::path::Path::new(path)
+            .extension()
+            .and_then(OsStr::to_str)
+            .unwrap_or_default()
+            .to_lowercase();
+
+        Ok(match ext.as_str() {
+            "png" => Self::Raster(RasterFormat::Png),
+            "jpg" | "jpeg" => Self::Raster(RasterFormat::Jpg),
+            "gif" => Self::Raster(RasterFormat::Gif),
+            "svg" | "svgz" => Self::Vector(VectorFormat::Svg),
+            _ => match &data {
+                Readable::Str(_) => Self::Vector(VectorFormat::Svg),
+                Readable::Bytes(bytes) => match RasterFormat::detect(bytes) {
+                    Some(f) => Self::Raster(f),
+                    None => bail!("unknown image format"),
+                },
+            },
+        })
+    }
+}
+
 /// A vector graphics format.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
 pub enum VectorFormat {
@@ -7,7 +7,9 @@ use ecow::{eco_format, EcoString};
 use image::codecs::gif::GifDecoder;
 use image::codecs::jpeg::JpegDecoder;
 use image::codecs::png::PngDecoder;
-use image::{guess_format, DynamicImage, ImageDecoder, ImageResult, Limits};
+use image::{
+    guess_format, DynamicImage, ExtendedColorType, ImageDecoder, ImageResult, Limits,
+};
 
 use crate::diag::{bail, StrResult};
 use crate::foundations::{Bytes, Cast};
@@ -22,6 +24,8 @@ struct Repr {
     format: RasterFormat,
     dynamic: image::DynamicImage,
     icc: Option<Vec<u8>>,
+    cmyk: bool,
+    rotation: Option<u32>,
     dpi: Option<f64>,
 }
 
@@ -31,16 +35,17 @@ impl RasterImage {
     pub fn new(data: Bytes, format: RasterFormat) -> StrResult<RasterImage> {
         fn decode_with<T: ImageDecoder>(
             decoder: ImageResult<T>,
-        ) -> ImageResult<(image::DynamicImage, Option<Vec<u8>>)> {
+        ) -> ImageResult<(image::DynamicImage, Option<Vec<u8>>, bool)> {
             let mut decoder = decoder?;
             let icc = decoder.icc_profile().ok().flatten().filter(|icc| !icc.is_empty());
+            let cmyk = decoder.original_color_type() == ExtendedColorType::Cmyk8;
             decoder.set_limits(Limits::default())?;
             let dynamic = image::DynamicImage::from_decoder(decoder)?;
-            Ok((dynamic, icc))
+            Ok((dynamic, icc, cmyk))
         }
 
         let cursor = io::Cursor::new(&data);
-        let (mut dynamic, icc) = match format {
+        let (mut dynamic, icc, cmyk) = match format {
             RasterFormat::Jpg => decode_with(JpegDecoder::new(cursor)),
             RasterFormat::Png => decode_with(PngDecoder::new(cursor)),
             RasterFormat::Gif => decode_with(GifDecoder::new(cursor)),
@@ -52,14 +57,16 @@ impl RasterImage {
             .ok();
 
         // Apply rotation from EXIF metadata.
-        if let Some(rotation) = exif.as_ref().and_then(exif_rotation) {
+        let rotation =
+            exif.as_ref().and_then(exif_rotation).filter(|r| (2..=8).contains(r));
+        if let Some(rotation) = rotation {
             apply_rotation(&mut dynamic, rotation);
         }
 
         // Extract pixel density.
         let dpi = determine_dpi(&data, exif.as_ref());
 
-        Ok(Self(Arc::new(Repr { data, format, dynamic, icc, dpi })))
+        Ok(Self(Arc::new(Repr { data, format, dynamic, icc, cmyk, rotation, dpi })))
     }
 
     /// The raw image data.
@@ -96,6 +103,20 @@ impl RasterImage {
     pub fn icc(&self) -> Option<&[u8]> {
         self.0.icc.as_deref()
     }
+
+    /// Whether the raw image data is in the CMYK color space.
+    ///
+    /// The [dynamic image](Self::dynamic) is always converted to RGB, but
+    /// exporters may use the raw data to preserve the original colors.
+    pub fn is_cmyk(&self) -> bool {
+        self.0.cmyk
+    }
+
+    /// The EXIF orientation that was applied to the [dynamic
+    /// image](Self::dynamic), if the raw image data is not upright.
+    pub fn rotation(&self) -> Option<u32> {
+        self.0.rotation
+    }
 }
 
 impl Hash for Repr {
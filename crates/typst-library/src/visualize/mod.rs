@@ -8,7 +8,9 @@ mod line;
 mod paint;
 mod path;
 mod polygon;
+mod rule;
 mod shape;
+mod spot;
 mod stroke;
 mod tiling;
 
@@ -20,7 +22,9 @@ pub use self::line::*;
 pub use self::paint::*;
 pub use self::path::*;
 pub use self::polygon::*;
+pub use self::rule::*;
 pub use self::shape::*;
+pub use self::spot::*;
 pub use self::stroke::*;
 pub use self::tiling::*;
 
@@ -40,9 +44,11 @@ pub(super) fn define(global: &mut Scope) {
     global.define_type::<Color>();
     global.define_type::<Gradient>();
     global.define_type::<Tiling>();
+    global.define_type::<Spot>();
     global.define_type::<Stroke>();
     global.define_elem::<ImageElem>();
     global.define_elem::<LineElem>();
+    global.define_elem::<RuleElem>();
     global.define_elem::<RectElem>();
     global.define_elem::<SquareElem>();
     global.define_elem::<EllipseElem>();
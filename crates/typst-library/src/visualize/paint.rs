@@ -3,7 +3,7 @@ use std::fmt::{self, Debug, Formatter};
 use ecow::EcoString;
 
 use crate::foundations::{cast, Repr, Smart};
-use crate::visualize::{Color, Gradient, RelativeTo, Tiling};
+use crate::visualize::{Color, Gradient, RelativeTo, Spot, Tiling};
 
 /// How a fill or stroke should be painted.
 #[derive(Clone, Eq, PartialEq, Hash)]
@@ -14,6 +14,8 @@ pub enum Paint {
     Gradient(Gradient),
     /// A tiling.
     Tiling(Tiling),
+    /// A named spot color.
+    Spot(Spot),
 }
 
 impl Paint {
@@ -21,6 +23,7 @@ impl Paint {
     pub fn unwrap_solid(&self) -> Color {
         match self {
             Self::Solid(color) => *color,
+            Self::Spot(spot) => spot.to_color(),
             Self::Gradient(_) | Self::Tiling(_) => panic!("expected solid color"),
         }
     }
@@ -28,7 +31,7 @@ impl Paint {
     /// Gets the relative coordinate system for this paint.
     pub fn relative(&self) -> Smart<RelativeTo> {
         match self {
-            Self::Solid(_) => Smart::Auto,
+            Self::Solid(_) | Self::Spot(_) => Smart::Auto,
             Self::Gradient(gradient) => gradient.relative(),
             Self::Tiling(tiling) => tiling.relative(),
         }
@@ -41,6 +44,7 @@ impl Paint {
     pub fn as_decoration(&self) -> Self {
         match self {
             Self::Solid(color) => Self::Solid(*color),
+            Self::Spot(spot) => Self::Spot(spot.clone()),
             Self::Gradient(gradient) => {
                 Self::Gradient(gradient.clone().with_relative(RelativeTo::Parent))
             }
@@ -57,6 +61,7 @@ impl Debug for Paint {
             Self::Solid(v) => v.fmt(f),
             Self::Gradient(v) => v.fmt(f),
             Self::Tiling(v) => v.fmt(f),
+            Self::Spot(v) => v.fmt(f),
         }
     }
 }
@@ -67,12 +72,19 @@ impl From<Tiling> for Paint {
     }
 }
 
+impl From<Spot> for Paint {
+    fn from(spot: Spot) -> Self {
+        Self::Spot(spot)
+    }
+}
+
 impl Repr for Paint {
     fn repr(&self) -> EcoString {
         match self {
             Self::Solid(color) => color.repr(),
             Self::Gradient(gradient) => gradient.repr(),
             Self::Tiling(tiling) => tiling.repr(),
+            Self::Spot(spot) => spot.repr(),
         }
     }
 }
@@ -95,8 +107,10 @@ cast! {
         Self::Solid(color) => color.into_value(),
         Self::Gradient(gradient) => gradient.into_value(),
         Self::Tiling(tiling) => tiling.into_value(),
+        Self::Spot(spot) => spot.into_value(),
     },
     color: Color => Self::Solid(color),
     gradient: Gradient => Self::Gradient(gradient),
     tiling: Tiling => Self::Tiling(tiling),
+    spot: Spot => Self::Spot(spot),
 }
@@ -0,0 +1,86 @@
+use crate::diag::SourceResult;
+use crate::engine::Engine;
+use crate::foundations::{elem, Content, NativeElement, Packed, Show, Smart, StyleChain};
+use crate::layout::{Abs, BlockBody, BlockElem, HAlignment, Length, Ratio, Rel, Spacing};
+use crate::text::TextElem;
+use crate::visualize::{LineElem, Stroke};
+
+/// A horizontal rule that separates blocks of content.
+///
+/// The rule is a block-level [line] that never breaks across pages. By
+/// default, it spans the full width of its container and takes on the text
+/// color.
+///
+/// # Example
+/// ```example
+/// = Introduction
+/// #lorem(10)
+///
+/// #rule()
+///
+/// = Background
+/// #lorem(10)
+///
+/// #rule(length: 50%, align: center, stroke: 2pt + eastern)
+/// ```
+#[elem(Show)]
+pub struct RuleElem {
+    /// The rule's length, relative to its container.
+    #[default(Ratio::one().into())]
+    pub length: Rel<Length>,
+
+    /// How to [stroke] the rule.
+    ///
+    /// If no paint is given, the rule is drawn in the current
+    /// [text color]($text.fill).
+    ///
+    /// ```example
+    /// #set text(maroon)
+    /// #rule()
+    /// #rule(stroke: (thickness: 2pt, dash: "dashed"))
+    /// ```
+    #[fold]
+    #[default(Stroke {
+        thickness: Smart::Custom(Abs::pt(0.5).into()),
+        ..Default::default()
+    })]
+    pub stroke: Stroke,
+
+    /// How to align the rule if it's shorter than its container.
+    pub align: HAlignment,
+
+    /// The spacing above and below the rule. If `{auto}`, the regular
+    /// [block spacing]($block.spacing) applies.
+    ///
+    /// ```example
+    /// A
+    /// #rule(spacing: 2em)
+    /// B
+    /// ```
+    pub spacing: Smart<Spacing>,
+}
+
+impl Show for Packed<RuleElem> {
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let mut stroke = self.stroke(styles);
+        if stroke.paint.is_auto() {
+            stroke.paint = Smart::Custom(TextElem::fill_in(styles).as_decoration());
+        }
+
+        let line = LineElem::new()
+            .with_length(self.length(styles))
+            .with_stroke(stroke)
+            .pack()
+            .spanned(self.span())
+            .aligned(self.align(styles).into());
+
+        let spacing = self.spacing(styles);
+        Ok(BlockElem::new()
+            .with_body(Some(BlockBody::Content(line)))
+            .with_breakable(false)
+            .with_above(spacing)
+            .with_below(spacing)
+            .pack()
+            .spanned(self.span()))
+    }
+}
@@ -118,6 +118,25 @@ pub struct RectElem {
     #[fold]
     pub outset: Sides<Option<Rel<Length>>>,
 
+    /// Whether to clip the content inside the rectangle.
+    ///
+    /// Clipping is useful when the rectangle's content is larger than the
+    /// rectangle itself, as any content that exceeds the rectangle's bounds
+    /// will be hidden. Rounded corners are taken into account.
+    ///
+    /// ```example
+    /// #rect(
+    ///   width: 50pt,
+    ///   height: 50pt,
+    ///   radius: 10pt,
+    ///   inset: 0pt,
+    ///   clip: true,
+    ///   image("tiger.jpg", width: 100pt, height: 100pt)
+    /// )
+    /// ```
+    #[default(false)]
+    pub clip: bool,
+
     /// The content to place into the rectangle.
     ///
     /// When this is omitted, the rectangle takes on a default size of at most
@@ -209,6 +228,12 @@ pub struct SquareElem {
     #[fold]
     pub outset: Sides<Option<Rel<Length>>>,
 
+    /// Whether to clip the content inside the square.
+    ///
+    /// See the [rectangle's documentation]($rect.clip) for more details.
+    #[default(false)]
+    pub clip: bool,
+
     /// The content to place into the square. The square expands to fit this
     /// content, keeping the 1-1 aspect ratio.
     ///
@@ -274,6 +299,24 @@ pub struct EllipseElem {
     #[fold]
     pub outset: Sides<Option<Rel<Length>>>,
 
+    /// Whether to clip the content inside the ellipse.
+    ///
+    /// Any content that exceeds the ellipse's outline will be hidden. When
+    /// clipping, the content is laid out in the ellipse's full bounding box
+    /// instead of the inscribed rectangle, so that it covers the whole ellipse.
+    ///
+    /// ```example
+    /// #ellipse(
+    ///   width: 80pt,
+    ///   height: 50pt,
+    ///   inset: 0pt,
+    ///   clip: true,
+    ///   image("tiger.jpg", width: 100pt, height: 100pt)
+    /// )
+    /// ```
+    #[default(false)]
+    pub clip: bool,
+
     /// The content to place into the ellipse.
     ///
     /// When this is omitted, the ellipse takes on a default size of at most
@@ -365,6 +408,21 @@ pub struct CircleElem {
     #[fold]
     pub outset: Sides<Option<Rel<Length>>>,
 
+    /// Whether to clip the content inside the circle.
+    ///
+    /// See the [ellipse's documentation]($ellipse.clip) for more details.
+    ///
+    /// ```example
+    /// #circle(
+    ///   radius: 25pt,
+    ///   inset: 0pt,
+    ///   clip: true,
+    ///   image("tiger.jpg", fit: "cover"),
+    /// )
+    /// ```
+    #[default(false)]
+    pub clip: bool,
+
     /// The content to place into the circle. The circle expands to fit this
     /// content, keeping the 1-1 aspect ratio.
     #[positional]
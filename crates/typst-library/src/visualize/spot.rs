@@ -0,0 +1,133 @@
+use ecow::{eco_format, EcoString};
+
+use crate::diag::{bail, StrResult};
+use crate::foundations::{func, repr, scope, ty, Repr};
+use crate::layout::Ratio;
+use crate::visualize::Color;
+
+/// A spot color for print production.
+///
+/// A spot color refers to a named ink (for example, a Pantone color) that a
+/// printer mixes separately instead of composing it from the process colors.
+/// Each spot color needs a _fallback_ color, which is used to display it on
+/// screen and by devices that do not have the named ink available.
+///
+/// When exporting to PDF, spot colors are written as _separation_ color
+/// spaces with the given name. In PNG and SVG export, the fallback color is
+/// used, lightened according to the tint.
+///
+/// # Example
+/// ```example
+/// #let brand = spot("PANTONE 300 C", cmyk(100%, 44%, 0%, 0%))
+///
+/// #rect(fill: brand, width: 100%, height: 20pt)
+/// #text(fill: spot("PANTONE 300 C", cmyk(100%, 44%, 0%, 0%), tint: 50%))[
+///   Tinted
+/// ]
+/// ```
+///
+/// # Overprint
+/// By default, a spot color knocks out the colors beneath it. Setting
+/// [`overprint`]($spot.overprint) instructs the printer to print the ink on
+/// top of the other inks instead. This is typically used to avoid gaps when
+/// the separations are slightly misregistered.
+///
+/// ```example
+/// #rect(
+///   width: 100%,
+///   height: 20pt,
+///   stroke: 2pt + spot("Varnish", luma(80%), overprint: true),
+/// )
+/// ```
+#[ty(scope, cast)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Spot {
+    /// The name of the ink.
+    name: EcoString,
+    /// The color used to display the ink.
+    fallback: Color,
+    /// How much of the ink to apply.
+    tint: Ratio,
+    /// Whether to overprint the inks beneath.
+    overprint: bool,
+}
+
+#[scope]
+impl Spot {
+    /// Create a new spot color.
+    ///
+    /// ```example
+    /// #let gold = spot("Gold", rgb("#d4af37"))
+    /// #square(size: 20pt, fill: gold)
+    /// ```
+    #[func(constructor)]
+    pub fn construct(
+        /// The name of the ink, as understood by the print shop.
+        name: EcoString,
+        /// The color used for display and by devices that lack the ink.
+        fallback: Color,
+        /// How much of the ink to apply, between `{0%}` and `{100%}`.
+        #[named]
+        #[default(Ratio::one())]
+        tint: Ratio,
+        /// Whether the ink is printed on top of the inks beneath it instead of
+        /// knocking them out. Only affects PDF export.
+        #[named]
+        #[default(false)]
+        overprint: bool,
+    ) -> StrResult<Spot> {
+        if name.is_empty() {
+            bail!("spot color name must not be empty");
+        }
+
+        if !(0.0..=1.0).contains(&tint.get()) {
+            bail!("tint must be between 0% and 100%");
+        }
+
+        Ok(Self { name, fallback, tint, overprint })
+    }
+
+    /// The name of the ink.
+    #[func]
+    pub fn name(&self) -> EcoString {
+        self.name.clone()
+    }
+
+    /// The color used to display the ink.
+    #[func]
+    pub fn fallback(&self) -> Color {
+        self.fallback
+    }
+
+    /// How much of the ink is applied.
+    #[func]
+    pub fn tint(&self) -> Ratio {
+        self.tint
+    }
+
+    /// Whether the ink overprints the inks beneath it.
+    #[func]
+    pub fn overprint(&self) -> bool {
+        self.overprint
+    }
+}
+
+impl Spot {
+    /// The color used to display the ink at its tint.
+    pub fn to_color(&self) -> Color {
+        self.fallback.lighten(Ratio::one() - self.tint)
+    }
+}
+
+impl Repr for Spot {
+    fn repr(&self) -> EcoString {
+        let mut args = vec![self.name.repr(), self.fallback.repr()];
+        if !self.tint.is_one() {
+            args.push(eco_format!("tint: {}", self.tint.repr()));
+        }
+        if self.overprint {
+            args.push("overprint: true".into());
+        }
+        eco_format!("spot{}", repr::pretty_array_like(&args, false))
+    }
+}
@@ -7,7 +7,7 @@ use crate::foundations::{
     Resolve, Smart, StyleChain, Value,
 };
 use crate::layout::{Abs, Length};
-use crate::visualize::{Color, Gradient, Paint, Tiling};
+use crate::visualize::{Color, Gradient, Paint, Spot, Tiling};
 
 /// Defines how to draw a line.
 ///
@@ -378,6 +378,10 @@ cast! {
         paint: Smart::Custom(tiling.into()),
         ..Default::default()
     },
+    spot: Spot => Self {
+        paint: Smart::Custom(spot.into()),
+        ..Default::default()
+    },
     mut dict: Dict => {
         // Get a value by key, accepting either Auto or something convertible to type T.
         fn take<T: FromValue>(dict: &mut Dict, key: &str) -> HintedStrResult<Smart<T>> {
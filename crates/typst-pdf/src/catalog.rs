@@ -12,7 +12,7 @@ use typst_syntax::Span;
 use xmp_writer::{DateTime, LangId, RenditionClass, XmpWriter};
 
 use crate::page::PdfPageLabel;
-use crate::{hash_base64, outline, TextStrExt, Timezone, WithEverything};
+use crate::{hash_base64, outline, tags, TextStrExt, Timezone, WithEverything};
 
 /// Write the document catalog.
 pub fn write_catalog(
@@ -20,12 +20,7 @@ pub fn write_catalog(
     pdf: &mut Pdf,
     alloc: &mut Ref,
 ) -> SourceResult<()> {
-    let lang = ctx
-        .resources
-        .languages
-        .iter()
-        .max_by_key(|(_, &count)| count)
-        .map(|(&l, _)| l);
+    let lang = ctx.resources.main_lang;
 
     let dir = if lang.map(Lang::dir) == Some(Dir::RTL) {
         Direction::R2L
@@ -39,6 +34,9 @@ pub fn write_catalog(
     // Write the page labels.
     let page_labels = write_page_labels(pdf, alloc, &ctx);
 
+    // Write the structure tree.
+    let struct_tree_root = tags::write_structure_tree(pdf, alloc, &ctx);
+
     // Write the document information.
     let info_ref = alloc.bump();
     let mut info = pdf.document_info(info_ref);
@@ -209,6 +207,11 @@ pub fn write_catalog(
         catalog.lang(TextStr(lang.as_str()));
     }
 
+    if let Some(struct_tree_root) = struct_tree_root {
+        catalog.pair(Name(b"StructTreeRoot"), struct_tree_root);
+        catalog.insert(Name(b"MarkInfo")).dict().pair(Name(b"Marked"), true);
+    }
+
     if ctx.options.standards.pdfa {
         catalog
             .output_intents()
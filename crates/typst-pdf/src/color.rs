@@ -1,9 +1,11 @@
 use std::sync::LazyLock;
 
 use arrayvec::ArrayVec;
-use pdf_writer::{writers, Chunk, Dict, Filter, Name, Ref};
+use ecow::{eco_format, EcoString};
+use pdf_writer::types::ColorSpaceOperand;
+use pdf_writer::{writers, Chunk, Dict, Filter, Name, Obj, Ref};
 use typst_library::diag::{bail, SourceResult};
-use typst_library::visualize::{Color, ColorSpace, Paint};
+use typst_library::visualize::{Color, ColorSpace, Paint, Spot};
 use typst_syntax::Span;
 
 use crate::{content, deflate, PdfChunk, PdfOptions, Renumber, WithResources};
@@ -25,6 +27,9 @@ pub struct ColorSpaces {
     use_srgb: bool,
     use_d65_gray: bool,
     use_linear_rgb: bool,
+    /// The separation color spaces of spot colors, identified by ink name
+    /// and fallback color.
+    spots: Vec<(EcoString, Color)>,
 }
 
 impl ColorSpaces {
@@ -48,6 +53,23 @@ impl ColorSpaces {
         }
     }
 
+    /// Register the separation color space of a spot color and return the
+    /// name under which it is available in the resources.
+    pub fn spot(&mut self, spot: &Spot) -> EcoString {
+        let key = (spot.name(), spot.fallback());
+        let index = match self.spots.iter().position(|v| *v == key) {
+            Some(index) => index,
+            None => {
+                if key.1.space() != ColorSpace::Cmyk {
+                    self.use_srgb = true;
+                }
+                self.spots.push(key);
+                self.spots.len() - 1
+            }
+        };
+        eco_format!("Sp{index}")
+    }
+
     /// Write the color spaces to the PDF file.
     pub fn write_color_spaces(&self, mut spaces: Dict, refs: &ColorFunctionRefs) {
         if self.use_srgb {
@@ -61,6 +83,11 @@ impl ColorSpaces {
         if self.use_linear_rgb {
             write(ColorSpace::LinearRgb, spaces.insert(LINEAR_SRGB).start(), refs);
         }
+
+        for (i, (name, fallback)) in self.spots.iter().enumerate() {
+            let key = eco_format!("Sp{i}");
+            write_separation(name, *fallback, spaces.insert(Name(key.as_bytes())), refs);
+        }
     }
 
     /// Write the necessary color spaces functions and ICC profiles to the
@@ -121,6 +148,37 @@ pub fn write(
     }
 }
 
+/// Write a separation color space for a spot color.
+///
+/// The alternate color space is used by devices that lack the named ink. The
+/// tint transform interpolates linearly from white to the fallback color.
+fn write_separation(name: &str, fallback: Color, obj: Obj, refs: &ColorFunctionRefs) {
+    let mut array = obj.array();
+    array.item(Name(b"Separation"));
+    array.item(Name(name.as_bytes()));
+
+    let (white, full): (&[f32], ArrayVec<f32, 4>) = match fallback {
+        Color::Cmyk(_) => {
+            array.item(Name(b"DeviceCMYK"));
+            (&[0.0; 4], ColorSpace::Cmyk.encode(fallback).into_iter().collect())
+        }
+        _ => {
+            let mut alternate = array.push().array();
+            alternate.item(Name(b"ICCBased"));
+            alternate.item(refs.srgb.unwrap());
+            alternate.finish();
+            (&[1.0; 3], ColorSpace::Srgb.encode(fallback).into_iter().take(3).collect())
+        }
+    };
+
+    let mut function = array.push().dict();
+    function.pair(Name(b"FunctionType"), 2);
+    function.insert(Name(b"Domain")).array().items([0.0_f32, 1.0]);
+    function.insert(Name(b"C0")).array().items(white.iter().copied());
+    function.insert(Name(b"C1")).array().items(full);
+    function.pair(Name(b"N"), 1.0_f32);
+}
+
 /// Global references for color conversion functions.
 ///
 /// These functions are only written once (at most, they are not written if not
@@ -223,6 +281,7 @@ impl PaintEncode for Paint {
             Self::Solid(c) => c.set_as_fill(ctx, on_text, transforms),
             Self::Gradient(gradient) => gradient.set_as_fill(ctx, on_text, transforms),
             Self::Tiling(tiling) => tiling.set_as_fill(ctx, on_text, transforms),
+            Self::Spot(spot) => spot.set_as_fill(ctx, on_text, transforms),
         }
     }
 
@@ -236,6 +295,7 @@ impl PaintEncode for Paint {
             Self::Solid(c) => c.set_as_stroke(ctx, on_text, transforms),
             Self::Gradient(gradient) => gradient.set_as_stroke(ctx, on_text, transforms),
             Self::Tiling(tiling) => tiling.set_as_stroke(ctx, on_text, transforms),
+            Self::Spot(spot) => spot.set_as_stroke(ctx, on_text, transforms),
         }
     }
 }
@@ -330,6 +390,46 @@ impl PaintEncode for Color {
     }
 }
 
+impl PaintEncode for Spot {
+    fn set_as_fill(
+        &self,
+        ctx: &mut content::Builder,
+        _: bool,
+        _: content::Transforms,
+    ) -> SourceResult<()> {
+        if self.fallback().space() == ColorSpace::Cmyk {
+            check_cmyk_allowed(ctx.options)?;
+        }
+
+        // The name is not static, so we bypass the color space caching.
+        let name = ctx.resources.colors.spot(self);
+        ctx.reset_fill_color_space();
+        ctx.content
+            .set_fill_color_space(ColorSpaceOperand::Named(Name(name.as_bytes())));
+        ctx.content.set_fill_color([self.tint().get() as f32]);
+        Ok(())
+    }
+
+    fn set_as_stroke(
+        &self,
+        ctx: &mut content::Builder,
+        _: bool,
+        _: content::Transforms,
+    ) -> SourceResult<()> {
+        if self.fallback().space() == ColorSpace::Cmyk {
+            check_cmyk_allowed(ctx.options)?;
+        }
+
+        // The name is not static, so we bypass the color space caching.
+        let name = ctx.resources.colors.spot(self);
+        ctx.reset_stroke_color_space();
+        ctx.content
+            .set_stroke_color_space(ColorSpaceOperand::Named(Name(name.as_bytes())));
+        ctx.content.set_stroke_color([self.tint().get() as f32]);
+        Ok(())
+    }
+}
+
 /// Extra color space functions.
 pub(super) trait ColorSpaceExt {
     /// Returns the range of the color space.
@@ -4,18 +4,21 @@
 //!
 //! See also [`pdf_writer::Content`].
 
+use std::num::NonZeroUsize;
+
 use ecow::eco_format;
 use pdf_writer::types::{
     ColorSpaceOperand, LineCapStyle, LineJoinStyle, TextRenderingMode,
 };
 use pdf_writer::writers::PositionedItems;
-use pdf_writer::{Content, Finish, Name, Rect, Str};
+use pdf_writer::{Content, Finish, Name, Rect, Str, TextStr};
 use typst_library::diag::{bail, error, SourceDiagnostic, SourceResult};
-use typst_library::foundations::Repr;
+use typst_library::foundations::{Repr, StyleChain};
+use typst_library::introspection::{Location, Tag};
 use typst_library::layout::{
     Abs, Em, Frame, FrameItem, GroupItem, Point, Ratio, Size, Transform,
 };
-use typst_library::model::Destination;
+use typst_library::model::{Destination, HeadingElem};
 use typst_library::text::color::should_outline;
 use typst_library::text::{Font, Glyph, TextItem, TextItemView};
 use typst_library::visualize::{
@@ -28,8 +31,9 @@ use typst_utils::{Deferred, Numeric, SliceExt};
 use crate::color::PaintEncode;
 use crate::color_font::ColorFontMap;
 use crate::extg::ExtGState;
-use crate::image::deferred_image;
+use crate::image::{deferred_image, Resample};
 use crate::resources::Resources;
+use crate::tags::{MarkedContent, StructKind, TextExtent};
 use crate::{deflate_deferred, AbsExt, ContentExt, EmExt, PdfOptions, StrExt};
 
 /// Encode a [`Frame`] into a content stream.
@@ -39,6 +43,9 @@ use crate::{deflate_deferred, AbsExt, ContentExt, EmExt, PdfOptions, StrExt};
 /// `color_glyph_width` should be `None` unless the `Frame` represents a [color
 /// glyph].
 ///
+/// If `tagged` is true, the content is marked up for the structure tree. This
+/// should only be the case for page contents.
+///
 /// [color glyph]: `crate::color_font`
 pub fn build(
     options: &PdfOptions,
@@ -46,9 +53,11 @@ pub fn build(
     frame: &Frame,
     fill: Option<Paint>,
     color_glyph_width: Option<f32>,
+    tagged: bool,
 ) -> SourceResult<Encoded> {
     let size = frame.size();
     let mut ctx = Builder::new(options, resources, size);
+    ctx.tagged = tagged;
 
     if let Some(width) = color_glyph_width {
         ctx.content.start_color_glyph(width);
@@ -64,7 +73,7 @@ pub fn build(
 
     if let Some(fill) = fill {
         let shape = Geometry::Rect(frame.size()).filled(fill);
-        write_shape(&mut ctx, Point::zero(), &shape)?;
+        write_artifact(&mut ctx, |ctx| write_shape(ctx, Point::zero(), &shape))?;
     }
 
     // Encode the frame into the content stream.
@@ -75,6 +84,7 @@ pub fn build(
         content: deflate_deferred(ctx.content.finish()),
         uses_opacities: ctx.uses_opacities,
         links: ctx.links,
+        marked: ctx.marked,
     })
 }
 
@@ -88,6 +98,8 @@ pub struct Encoded {
     pub uses_opacities: bool,
     /// Links in the PDF coordinate system.
     pub links: Vec<(Destination, Rect)>,
+    /// The marked content for the structure tree, in order of MCID.
+    pub marked: Vec<MarkedContent>,
 }
 
 /// An exporter for a single PDF content stream.
@@ -112,6 +124,12 @@ pub struct Builder<'a, R = ()> {
     uses_opacities: bool,
     /// All clickable links that are present in this content.
     links: Vec<(Destination, Rect)>,
+    /// Whether to mark up content for the structure tree.
+    tagged: bool,
+    /// The marked content written so far.
+    marked: Vec<MarkedContent>,
+    /// The headings whose content is currently being written.
+    headings: Vec<(Location, NonZeroUsize)>,
 }
 
 impl<'a, R> Builder<'a, R> {
@@ -129,6 +147,9 @@ impl<'a, R> Builder<'a, R> {
             state: State::new(size),
             saves: vec![],
             links: vec![],
+            tagged: false,
+            marked: vec![],
+            headings: vec![],
         }
     }
 }
@@ -229,22 +250,30 @@ impl Builder<'_, ()> {
         let get_opacity = |paint: &Paint| {
             let color = match paint {
                 Paint::Solid(color) => *color,
+                Paint::Spot(spot) => spot.fallback(),
                 Paint::Gradient(_) | Paint::Tiling(_) => return 255,
             };
 
             color.alpha().map_or(255, |v| (v * 255.0).round() as u8)
         };
 
+        let get_overprint =
+            |paint: &Paint| matches!(paint, Paint::Spot(spot) if spot.overprint());
+
         let stroke_opacity = stroke.map_or(255, |stroke| get_opacity(&stroke.paint));
         let fill_opacity = fill.map_or(255, get_opacity);
-        self.set_external_graphics_state(&ExtGState { stroke_opacity, fill_opacity });
+        let stroke_overprint = stroke.is_some_and(|stroke| get_overprint(&stroke.paint));
+        let fill_overprint = fill.is_some_and(get_overprint);
+        self.set_external_graphics_state(&ExtGState {
+            stroke_opacity,
+            fill_opacity,
+            stroke_overprint,
+            fill_overprint,
+        });
     }
 
     fn reset_opacities(&mut self) {
-        self.set_external_graphics_state(&ExtGState {
-            stroke_opacity: 255,
-            fill_opacity: 255,
-        });
+        self.set_external_graphics_state(&ExtGState::default());
     }
 
     pub fn transform(&mut self, transform: Transform) {
@@ -375,17 +404,82 @@ pub(crate) fn write_frame(ctx: &mut Builder, frame: &Frame) -> SourceResult<()>
         match item {
             FrameItem::Group(group) => write_group(ctx, pos, group)?,
             FrameItem::Text(text) => write_text(ctx, pos, text)?,
-            FrameItem::Shape(shape, _) => write_shape(ctx, pos, shape)?,
+            FrameItem::Shape(shape, _) => {
+                write_artifact(ctx, |ctx| write_shape(ctx, pos, shape))?
+            }
             FrameItem::Image(image, size, span) => {
                 write_image(ctx, x, y, image, *size, *span)?
             }
             FrameItem::Link(dest, size) => write_link(ctx, pos, dest, *size),
-            FrameItem::Tag(_) => {}
+            FrameItem::Tag(tag) => write_tag(ctx, tag),
         }
     }
     Ok(())
 }
 
+/// Keep track of the headings whose content is being written.
+fn write_tag(ctx: &mut Builder, tag: &Tag) {
+    if !ctx.tagged {
+        return;
+    }
+
+    match tag {
+        Tag::Start(elem) => {
+            if let Some(heading) = elem.to_packed::<HeadingElem>() {
+                // Headings are synthesized with their resolved level, so the
+                // element itself carries it and no styles are needed.
+                let level = heading.resolve_level(StyleChain::default());
+                ctx.headings.push((elem.location().unwrap(), level));
+            }
+        }
+        Tag::End(loc, _) => {
+            if ctx.headings.last().is_some_and(|&(last, _)| last == *loc) {
+                ctx.headings.pop();
+            }
+        }
+    }
+}
+
+/// Encode content that belongs to the structure element of the given kind.
+fn write_marked<F>(ctx: &mut Builder, kind: StructKind, f: F) -> SourceResult<()>
+where
+    F: FnOnce(&mut Builder) -> SourceResult<()>,
+{
+    if !ctx.tagged {
+        return f(ctx);
+    }
+
+    let mcid = ctx.marked.len() as i32;
+    let mut marked = ctx.content.begin_marked_content_with_properties(kind.role());
+    let mut properties = marked.properties();
+    properties.pair(Name(b"MCID"), mcid);
+    if let StructKind::Figure(Some(alt)) = &kind {
+        properties.pair(Name(b"Alt"), TextStr(alt.as_str()));
+    }
+    properties.finish();
+    marked.finish();
+
+    ctx.marked.push(MarkedContent { mcid, kind });
+    f(ctx)?;
+    ctx.content.end_marked_content();
+    Ok(())
+}
+
+/// Encode content that is not part of the structure tree, like decorations.
+fn write_artifact<F>(ctx: &mut Builder, f: F) -> SourceResult<()>
+where
+    F: FnOnce(&mut Builder) -> SourceResult<()>,
+{
+    if !ctx.tagged {
+        return f(ctx);
+    }
+
+    ctx.content.begin_marked_content(Name(b"Artifact"));
+    f(ctx)?;
+    ctx.content.end_marked_content();
+    Ok(())
+}
+
 /// Encode a group into the content stream.
 fn write_group(ctx: &mut Builder, pos: Point, group: &GroupItem) -> SourceResult<()> {
     let translation = Transform::translate(pos.x, pos.y);
@@ -426,6 +520,43 @@ fn write_text(ctx: &mut Builder, pos: Point, text: &TextItem) -> SourceResult<()
         );
     }
 
+    // Text in another language than the document's main language is tagged
+    // with its language so that PDF readers (in particular, screen readers)
+    // can handle it accordingly.
+    let tag_lang = ctx.resources.main_lang.is_some_and(|lang| lang != text.lang);
+    if tag_lang {
+        ctx.content
+            .begin_marked_content_with_properties(Name(b"Span"))
+            .properties()
+            .pair(Name(b"Lang"), TextStr(text.lang.as_str()));
+    }
+
+    let kind = match ctx.headings.last() {
+        Some(&(loc, level)) => StructKind::Heading(loc, level),
+        None => StructKind::Paragraph(text_extent(ctx, pos, text)),
+    };
+    write_marked(ctx, kind, |ctx| write_text_runs(ctx, pos, text))?;
+
+    if tag_lang {
+        ctx.content.end_marked_content();
+    }
+
+    Ok(())
+}
+
+/// Determine the vertical extent of a text run on the page.
+fn text_extent(ctx: &Builder, pos: Point, text: &TextItem) -> TextExtent {
+    // The current transform maps into PDF coordinates, where the Y axis goes
+    // upwards, so we flip the sign to measure downwards.
+    let cap_height = text.font.metrics().cap_height.at(text.size);
+    let top = Point::new(pos.x, pos.y - cap_height).transform(ctx.state.transform);
+    let bottom = pos.transform(ctx.state.transform);
+    TextExtent { top: -top.y, bottom: -bottom.y, size: text.size }
+}
+
+/// Encode a text run into the content stream, splitting it into sub-runs of
+/// normal and color glyphs if necessary.
+fn write_text_runs(ctx: &mut Builder, pos: Point, text: &TextItem) -> SourceResult<()> {
     let outline_glyphs =
         text.glyphs.iter().filter(|g| should_outline(&text.font, g)).count();
 
@@ -726,10 +857,21 @@ fn write_image(
     size: Size,
     span: Span,
 ) -> SourceResult<()> {
-    let index = ctx.resources.images.insert(image.clone());
+    // The size of the image on the page determines its effective resolution.
+    let ts = ctx.state.transform;
+    let placed = Size::new(
+        size.x * ts.sx.get().hypot(ts.ky.get()),
+        size.y * ts.kx.get().hypot(ts.sy.get()),
+    );
+    let resample = ctx
+        .options
+        .downsample
+        .as_ref()
+        .and_then(|downsample| Resample::new(image, placed, downsample));
+    let index = ctx.resources.images.insert((image.clone(), resample));
     ctx.resources.deferred_images.entry(index).or_insert_with(|| {
         let (image, color_space) =
-            deferred_image(image.clone(), ctx.options.standards.pdfa);
+            deferred_image(image.clone(), ctx.options.standards.pdfa, resample);
         if let Some(color_space) = color_space {
             ctx.resources.colors.mark_as_used(color_space);
         }
@@ -748,7 +890,15 @@ fn write_image(
         if ctx.options.standards.pdfa && alt.len() > Str::PDFA_LIMIT {
             bail!(span, "the image's alt text is too long");
         }
+    }
 
+    if ctx.tagged {
+        let alt = image.alt().map(Into::into);
+        write_marked(ctx, StructKind::Figure(alt), |ctx| {
+            ctx.content.x_object(Name(name.as_bytes()));
+            Ok(())
+        })?;
+    } else if let Some(alt) = image.alt() {
         let mut image_span =
             ctx.content.begin_marked_content_with_properties(Name(b"Span"));
         let mut image_alt = image_span.properties();
@@ -12,11 +12,20 @@ pub struct ExtGState {
     pub stroke_opacity: u8,
     // In the range 0-255, needs to be divided before being written into the graphics state!
     pub fill_opacity: u8,
+    // Whether strokes overprint the inks beneath them.
+    pub stroke_overprint: bool,
+    // Whether fills overprint the inks beneath them.
+    pub fill_overprint: bool,
 }
 
 impl Default for ExtGState {
     fn default() -> Self {
-        Self { stroke_opacity: 255, fill_opacity: 255 }
+        Self {
+            stroke_opacity: 255,
+            fill_opacity: 255,
+            stroke_overprint: false,
+            fill_overprint: false,
+        }
     }
 }
 
@@ -40,10 +49,18 @@ pub fn write_graphic_states(
 
             let id = chunk.alloc();
             out.insert(*external_gs, id);
-            chunk
-                .ext_graphics(id)
+            let mut ext_gs = chunk.ext_graphics(id);
+            ext_gs
                 .non_stroking_alpha(external_gs.fill_opacity as f32 / 255.0)
                 .stroking_alpha(external_gs.stroke_opacity as f32 / 255.0);
+
+            // The fill overprint flag defaults to the stroke one, so both
+            // need to be written as soon as either is set.
+            if external_gs.stroke_overprint || external_gs.fill_overprint {
+                ext_gs
+                    .overprint(external_gs.stroke_overprint)
+                    .overprint_fill(external_gs.fill_overprint);
+            }
         }
 
         Ok(())
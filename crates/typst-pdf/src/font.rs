@@ -7,8 +7,8 @@ use pdf_writer::types::{CidFontType, FontFlags, SystemInfo, UnicodeCmap};
 use pdf_writer::writers::{FontDescriptor, WMode};
 use pdf_writer::{Chunk, Filter, Finish, Name, Rect, Ref, Str};
 use subsetter::GlyphRemapper;
-use ttf_parser::{name_id, GlyphId, Tag};
-use typst_library::diag::{At, SourceResult};
+use ttf_parser::{name_id, GlyphId, Permissions, Tag};
+use typst_library::diag::{bail, At, SourceResult};
 use typst_library::text::Font;
 use typst_syntax::Span;
 use typst_utils::SliceExt;
@@ -122,12 +122,22 @@ pub fn write_fonts(
                 .writing_mode(WMode::Horizontal)
                 .filter(Filter::FlateDecode);
 
+            let postscript_name = font.find_name(name_id::POST_SCRIPT_NAME);
+            let name = postscript_name.as_deref().unwrap_or(&font.info().family);
+
+            // PDF/A requires all fonts to be legally embeddable.
+            if context.options.standards.pdfa
+                && matches!(ttf.permissions(), Some(Permissions::Restricted))
+            {
+                bail!(
+                    Span::detached(),
+                    "the font {name} may not be embedded in PDF/A export";
+                    hint: "its license forbids embedding, try using a different font",
+                );
+            }
+
             let subset = subset_font(font, glyph_remapper)
-                .map_err(|err| {
-                    let postscript_name = font.find_name(name_id::POST_SCRIPT_NAME);
-                    let name = postscript_name.as_deref().unwrap_or(&font.info().family);
-                    eco_format!("failed to process font {name}: {err}")
-                })
+                .map_err(|err| eco_format!("failed to process font {name}: {err}"))
                 .at(Span::detached())?;
 
             let mut stream = chunk.stream(data_ref, &subset);
@@ -2,15 +2,21 @@ use std::collections::HashMap;
 use std::io::Cursor;
 
 use ecow::eco_format;
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
 use image::{DynamicImage, GenericImageView, Rgba};
 use pdf_writer::{Chunk, Filter, Finish, Ref};
 use typst_library::diag::{At, SourceResult, StrResult};
+use typst_library::layout::Size;
 use typst_library::visualize::{
     ColorSpace, Image, ImageKind, RasterFormat, RasterImage, SvgImage,
 };
 use typst_utils::Deferred;
 
-use crate::{color, deflate, PdfChunk, WithGlobalRefs};
+use crate::{color, deflate, Downsample, PdfChunk, WithGlobalRefs};
+
+/// An image as it is embedded into the PDF, possibly at a reduced resolution.
+pub type PdfImage = (Image, Option<Resample>);
 
 /// Embed all used images into the PDF.
 #[typst_macros::time(name = "write images")]
@@ -32,7 +38,7 @@ pub fn write_images(
                 EncodedImage::Raster {
                     data,
                     filter,
-                    has_color,
+                    color,
                     width,
                     height,
                     icc,
@@ -47,24 +53,32 @@ pub fn write_images(
                     image.height(*height as i32);
                     image.bits_per_component(8);
 
+                    // Adobe applications write CMYK JPEGs with inverted
+                    // components.
+                    if let EncodedColor::Cmyk { inverted: true } = color {
+                        image.decode([1.0, 0.0].repeat(4));
+                    }
+
                     let mut icc_ref = None;
                     let space = image.color_space();
                     if icc.is_some() {
                         let id = chunk.alloc.bump();
                         space.icc_based(id);
                         icc_ref = Some(id);
-                    } else if *has_color {
-                        color::write(
-                            ColorSpace::Srgb,
-                            space,
-                            &context.globals.color_functions,
-                        );
                     } else {
-                        color::write(
-                            ColorSpace::D65Gray,
-                            space,
-                            &context.globals.color_functions,
-                        );
+                        match color {
+                            EncodedColor::Gray => color::write(
+                                ColorSpace::D65Gray,
+                                space,
+                                &context.globals.color_functions,
+                            ),
+                            EncodedColor::Rgb => color::write(
+                                ColorSpace::Srgb,
+                                space,
+                                &context.globals.color_functions,
+                            ),
+                            EncodedColor::Cmyk { .. } => space.device_cmyk(),
+                        }
                     }
 
                     // Add a second gray-scale image containing the alpha values if
@@ -87,12 +101,19 @@ pub fn write_images(
                     if let (Some(icc), Some(icc_ref)) = (icc, icc_ref) {
                         let mut stream = chunk.icc_profile(icc_ref, icc);
                         stream.filter(Filter::FlateDecode);
-                        if *has_color {
-                            stream.n(3);
-                            stream.alternate().srgb();
-                        } else {
-                            stream.n(1);
-                            stream.alternate().d65_gray();
+                        match color {
+                            EncodedColor::Gray => {
+                                stream.n(1);
+                                stream.alternate().d65_gray();
+                            }
+                            EncodedColor::Rgb => {
+                                stream.n(3);
+                                stream.alternate().srgb();
+                            }
+                            EncodedColor::Cmyk { .. } => {
+                                stream.n(4);
+                                stream.alternate().device_cmyk();
+                            }
                         }
                     }
                 }
@@ -119,9 +140,15 @@ pub fn write_images(
 pub fn deferred_image(
     image: Image,
     pdfa: bool,
+    resample: Option<Resample>,
 ) -> (Deferred<StrResult<EncodedImage>>, Option<ColorSpace>) {
     let color_space = match image.kind() {
-        ImageKind::Raster(raster) if raster.icc().is_none() => {
+        ImageKind::Raster(raster)
+            if resample.is_none() && passthrough_cmyk(raster, pdfa) =>
+        {
+            None
+        }
+        ImageKind::Raster(raster) if raster.icc().is_none() || raster.is_cmyk() => {
             if raster.dynamic().color().channel_count() > 2 {
                 Some(ColorSpace::Srgb)
             } else {
@@ -134,22 +161,39 @@ pub fn deferred_image(
     let deferred = Deferred::new(move || match image.kind() {
         ImageKind::Raster(raster) => {
             let raster = raster.clone();
-            let (width, height) = (raster.width(), raster.height());
-            let (data, filter, has_color) = encode_raster_image(&raster);
-            let icc = raster.icc().map(deflate);
-
-            let alpha =
-                raster.dynamic().color().has_alpha().then(|| encode_alpha(&raster));
-
-            Ok(EncodedImage::Raster {
-                data,
-                filter,
-                has_color,
-                width,
-                height,
-                icc,
-                alpha,
-            })
+            let resized;
+            let dynamic = match resample {
+                Some(resample) => {
+                    resized = raster.dynamic().resize_exact(
+                        resample.width,
+                        resample.height,
+                        FilterType::Lanczos3,
+                    );
+                    &resized
+                }
+                None => raster.dynamic(),
+            };
+
+            let (width, height) = (dynamic.width(), dynamic.height());
+            let passthrough = resample.is_none() && passthrough_cmyk(&raster, pdfa);
+            let (data, filter, color) = if passthrough {
+                let inverted = is_adobe_jpeg(raster.data());
+                (
+                    raster.data().to_vec(),
+                    Filter::DctDecode,
+                    EncodedColor::Cmyk { inverted },
+                )
+            } else {
+                encode_raster_image(&raster, dynamic, resample)
+            };
+
+            // A CMYK profile does not fit the RGB-converted image data.
+            let icc =
+                raster.icc().filter(|_| !raster.is_cmyk() || passthrough).map(deflate);
+
+            let alpha = dynamic.color().has_alpha().then(|| encode_alpha(dynamic));
+
+            Ok(EncodedImage::Raster { data, filter, color, width, height, icc, alpha })
         }
         ImageKind::Svg(svg) => {
             let (chunk, id) = encode_svg(svg, pdfa)
@@ -161,20 +205,68 @@ pub fn deferred_image(
     (deferred, color_space)
 }
 
-/// Encode an image with a suitable filter and return the data, filter and
-/// whether the image has color.
+/// Whether to embed the raw data of a CMYK JPEG instead of converting it to
+/// RGB.
+///
+/// This is not possible if the decoded image was rotated. In PDF/A, a
+/// device-dependent color space is only allowed with an ICC profile.
+fn passthrough_cmyk(raster: &RasterImage, pdfa: bool) -> bool {
+    raster.format() == RasterFormat::Jpg
+        && raster.is_cmyk()
+        && raster.rotation().is_none()
+        && (!pdfa || raster.icc().is_some())
+}
+
+/// Whether a JPEG contains an Adobe APP14 marker segment.
+///
+/// Such JPEGs store CMYK data with inverted components.
+fn is_adobe_jpeg(data: &[u8]) -> bool {
+    let mut rest = data.strip_prefix(&[0xFF, 0xD8]).unwrap_or_default();
+    while let [0xFF, marker, hi, lo, ..] = *rest {
+        // Image data starts after the start-of-scan marker.
+        if marker == 0xDA {
+            break;
+        }
+
+        let len = usize::from(u16::from_be_bytes([hi, lo]));
+        let Some(segment) = rest.get(4..2 + len) else { break };
+        if marker == 0xEE && segment.starts_with(b"Adobe") {
+            return true;
+        }
+
+        rest = &rest[2 + len..];
+    }
+    false
+}
+
+/// Encode an image's pixels with a suitable filter and return the data, filter
+/// and color of the encoded data.
 ///
+/// The pixels are either the image's own or a downsampled version of them.
 /// Skips the alpha channel as that's encoded separately.
 #[typst_macros::time(name = "encode raster image")]
-fn encode_raster_image(image: &RasterImage) -> (Vec<u8>, Filter, bool) {
-    let dynamic = image.dynamic();
+fn encode_raster_image(
+    image: &RasterImage,
+    dynamic: &DynamicImage,
+    resample: Option<Resample>,
+) -> (Vec<u8>, Filter, EncodedColor) {
     let channel_count = dynamic.color().channel_count();
-    let has_color = channel_count > 2;
+    let color = if channel_count > 2 { EncodedColor::Rgb } else { EncodedColor::Gray };
+    let is_jpg = image.format() == RasterFormat::Jpg;
 
-    if image.format() == RasterFormat::Jpg {
+    if let Some(resample) = resample.filter(|resample| is_jpg || !resample.lossless) {
+        let mut data = vec![];
+        let mut encoder = JpegEncoder::new_with_quality(&mut data, resample.jpeg_quality);
+        match color {
+            EncodedColor::Gray => encoder.encode_image(&dynamic.to_luma8()),
+            _ => encoder.encode_image(&dynamic.to_rgb8()),
+        }
+        .unwrap();
+        (data, Filter::DctDecode, color)
+    } else if is_jpg {
         let mut data = Cursor::new(vec![]);
         dynamic.write_to(&mut data, image::ImageFormat::Jpeg).unwrap();
-        (data.into_inner(), Filter::DctDecode, has_color)
+        (data.into_inner(), Filter::DctDecode, color)
     } else {
         // TODO: Encode flate streams with PNG-predictor?
         let data = match (dynamic, channel_count) {
@@ -185,18 +277,14 @@ fn encode_raster_image(image: &RasterImage) -> (Vec<u8>, Filter, bool) {
             // Anything else
             _ => deflate(dynamic.to_rgb8().as_raw()),
         };
-        (data, Filter::FlateDecode, has_color)
+        (data, Filter::FlateDecode, color)
     }
 }
 
 /// Encode an image's alpha channel if present.
 #[typst_macros::time(name = "encode alpha")]
-fn encode_alpha(raster: &RasterImage) -> (Vec<u8>, Filter) {
-    let pixels: Vec<_> = raster
-        .dynamic()
-        .pixels()
-        .map(|(_, _, Rgba([_, _, _, a]))| a)
-        .collect();
+fn encode_alpha(dynamic: &DynamicImage) -> (Vec<u8>, Filter) {
+    let pixels: Vec<_> = dynamic.pixels().map(|(_, _, Rgba([_, _, _, a]))| a).collect();
     (deflate(&pixels), Filter::FlateDecode)
 }
 
@@ -224,8 +312,8 @@ pub enum EncodedImage {
         data: Vec<u8>,
         /// The filter to use for the image.
         filter: Filter,
-        /// Whether the image has color.
-        has_color: bool,
+        /// The color space of the encoded data.
+        color: EncodedColor,
         /// The image's width.
         width: u32,
         /// The image's height.
@@ -240,3 +328,106 @@ pub enum EncodedImage {
     /// The chunk is the SVG converted to PDF objects.
     Svg(Chunk, Ref),
 }
+
+/// How to downsample a raster image before embedding it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Resample {
+    /// The target pixel width.
+    width: u32,
+    /// The target pixel height.
+    height: u32,
+    /// The quality for re-encoding as JPEG.
+    jpeg_quality: u8,
+    /// Whether images in lossless formats stay lossless.
+    lossless: bool,
+}
+
+impl Resample {
+    /// Determine how to downsample an image that is placed at the given size.
+    ///
+    /// Returns `None` if the image is not a raster image or if its resolution
+    /// at the placed size doesn't exceed the requested one.
+    pub fn new(image: &Image, size: Size, downsample: &Downsample) -> Option<Self> {
+        let ImageKind::Raster(raster) = image.kind() else { return None };
+        let (width, height) = (f64::from(raster.width()), f64::from(raster.height()));
+
+        // Pick the factor such that both axes keep the requested resolution.
+        let factor = (size.x.to_inches() * downsample.ppi / width)
+            .max(size.y.to_inches() * downsample.ppi / height);
+        if !factor.is_finite() || factor >= 1.0 {
+            return None;
+        }
+
+        Some(Self {
+            width: ((width * factor).ceil() as u32).max(1),
+            height: ((height * factor).ceil() as u32).max(1),
+            jpeg_quality: downsample.jpeg_quality.clamp(1, 100),
+            lossless: downsample.lossless,
+        })
+    }
+}
+
+/// The color space of a pre-encoded raster image's data.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum EncodedColor {
+    /// One gray component.
+    Gray,
+    /// Three RGB components.
+    Rgb,
+    /// Four CMYK components, which are possibly inverted.
+    Cmyk { inverted: bool },
+}
+
+#[cfg(test)]
+mod tests {
+    use typst_library::layout::Abs;
+    use typst_library::visualize::ImageFormat;
+
+    use super::*;
+
+    #[test]
+    fn test_resample() {
+        let mut data = Cursor::new(vec![]);
+        DynamicImage::new_rgb8(600, 300)
+            .write_to(&mut data, image::ImageFormat::Png)
+            .unwrap();
+        let format = ImageFormat::Raster(RasterFormat::Png);
+        let image = Image::new(data.into_inner().into(), format, None).unwrap();
+        let downsample = Downsample { ppi: 150.0, ..Downsample::default() };
+        let resample = |w, h| {
+            Resample::new(&image, Size::new(Abs::inches(w), Abs::inches(h)), &downsample)
+                .map(|resample| (resample.width, resample.height))
+        };
+
+        // At or below the requested resolution.
+        assert_eq!(resample(4.0, 2.0), None);
+        assert_eq!(resample(8.0, 4.0), None);
+
+        // Both axes keep at least the requested resolution.
+        assert_eq!(resample(2.0, 1.0), Some((300, 150)));
+        assert_eq!(resample(2.0, 0.5), Some((300, 150)));
+        assert_eq!(resample(1.0, 1.0), Some((300, 150)));
+        assert_eq!(resample(0.0, 0.0), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_is_adobe_jpeg() {
+        const SOI: [u8; 2] = [0xFF, 0xD8];
+        const APP0: [u8; 9] = [0xFF, 0xE0, 0x00, 0x07, b'J', b'F', b'I', b'F', 0];
+        const APP14: [u8; 11] =
+            [0xFF, 0xEE, 0x00, 0x09, b'A', b'd', b'o', b'b', b'e', 0x00, 0x64];
+        const SOS: [u8; 4] = [0xFF, 0xDA, 0x00, 0x02];
+
+        assert!(is_adobe_jpeg(&[&SOI[..], &APP0, &APP14, &SOS].concat()));
+        assert!(is_adobe_jpeg(&[&SOI[..], &APP14].concat()));
+        assert!(!is_adobe_jpeg(&[&SOI[..], &APP0, &SOS].concat()));
+
+        // Markers after the start of scan belong to the image data.
+        assert!(!is_adobe_jpeg(&[&SOI[..], &SOS, &APP14].concat()));
+
+        // Truncated segments and non-JPEG data.
+        assert!(!is_adobe_jpeg(&[&SOI[..], &APP14[..8]].concat()));
+        assert!(!is_adobe_jpeg(&APP14));
+        assert!(!is_adobe_jpeg(&[]));
+    }
+}
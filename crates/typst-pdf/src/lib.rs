@@ -12,6 +12,7 @@ mod named_destination;
 mod outline;
 mod page;
 mod resources;
+mod tags;
 mod tiling;
 
 use std::collections::HashMap;
@@ -26,7 +27,6 @@ use typst_library::diag::{bail, SourceResult, StrResult};
 use typst_library::foundations::{Datetime, Smart};
 use typst_library::layout::{Abs, Em, PageRanges, PagedDocument, Transform};
 use typst_library::text::Font;
-use typst_library::visualize::Image;
 use typst_syntax::Span;
 use typst_utils::Deferred;
 
@@ -36,7 +36,7 @@ use crate::color_font::{write_color_fonts, ColorFontSlice};
 use crate::extg::{write_graphic_states, ExtGState};
 use crate::font::write_fonts;
 use crate::gradient::{write_gradients, PdfGradient};
-use crate::image::write_images;
+use crate::image::{write_images, PdfImage};
 use crate::named_destination::{write_named_destinations, NamedDestinations};
 use crate::page::{alloc_page_refs, traverse_pages, write_page_tree, EncodedPage};
 use crate::resources::{
@@ -97,6 +97,39 @@ pub struct PdfOptions<'a> {
     pub page_ranges: Option<PageRanges>,
     /// A list of PDF standards that Typst will enforce conformance with.
     pub standards: PdfStandards,
+    /// If not `None`, raster images with a higher resolution than requested
+    /// at their placed size are downsampled before they are embedded.
+    pub downsample: Option<Downsample>,
+    /// Whether to produce a tagged PDF with a structure tree. Headings,
+    /// paragraphs, and images are tagged so that assistive technology can
+    /// navigate the document. Paragraphs are recognized by the spacing between
+    /// their lines. This makes the PDF larger and is thus opt-in.
+    pub tagged: bool,
+}
+
+/// Settings for downsampling raster images during PDF export.
+///
+/// The export itself does not report how many bytes downsampling saved. To
+/// measure the effect, compare the size of exports with and without
+/// downsampling, as the CLI does.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Downsample {
+    /// The resolution in pixels per inch at which images are embedded.
+    ///
+    /// Images whose resolution at their placed size is already at or below
+    /// this value are kept as is.
+    pub ppi: f64,
+    /// The quality (between 1 and 100) for re-encoding downsampled JPEGs.
+    pub jpeg_quality: u8,
+    /// Whether downsampled images in lossless formats stay lossless. If
+    /// `false`, they are re-encoded as JPEGs, too.
+    pub lossless: bool,
+}
+
+impl Default for Downsample {
+    fn default() -> Self {
+        Self { ppi: 300.0, jpeg_quality: 85, lossless: true }
+    }
 }
 
 /// A timestamp with timezone information.
@@ -309,7 +342,7 @@ struct References {
     /// The IDs of written color fonts.
     color_fonts: HashMap<ColorFontSlice, Ref>,
     /// The IDs of written images.
-    images: HashMap<Image, Ref>,
+    images: HashMap<PdfImage, Ref>,
     /// The IDs of written gradients.
     gradients: HashMap<PdfGradient, Ref>,
     /// The IDs of written tilings.
@@ -660,8 +693,434 @@ fn transform_to_array(ts: Transform) -> [f32; 6] {
 
 #[cfg(test)]
 mod tests {
+    use std::num::NonZeroUsize;
+
+    use typst_library::diag::{FileError, FileResult};
+    use typst_library::foundations::{Bytes, Content, NativeElement};
+    use typst_library::introspection::{Location, Tag};
+    use typst_library::layout::{Frame, FrameItem, Page, Point, Ratio, Size};
+    use typst_library::model::HeadingElem;
+    use typst_library::text::{FontBook, Glyph, Lang, TextElem, TextItem};
+    use typst_library::visualize::{
+        Cmyk, Color, Geometry, Image, ImageFormat, RasterFormat, Spot,
+    };
+    use typst_library::{Library, World};
+    use typst_syntax::{FileId, Source};
+    use typst_utils::LazyHash;
+
     use super::*;
 
+    /// A single-page document with the given page contents.
+    fn page(frame: Frame) -> PagedDocument {
+        PagedDocument {
+            pages: vec![Page {
+                frame,
+                fill: Smart::Auto,
+                numbering: None,
+                supplement: Content::empty(),
+                number: 1,
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn document() -> PagedDocument {
+        let size = Size::new(Abs::pt(100.0), Abs::pt(100.0));
+        let mut frame = Frame::hard(size);
+        let shape = Geometry::Rect(size / 2.0).filled(Color::BLACK);
+        frame.push(Point::zero(), FrameItem::Shape(shape, Span::detached()));
+        page(frame)
+    }
+
+    /// A text run with a single glyph in a font that covers basic Latin.
+    fn text(text: &str) -> FrameItem {
+        let font = typst_dev_assets::fonts()
+            .flat_map(|data| Font::iter(Bytes::from_static(data)))
+            .find(|font| font.ttf().glyph_index('a').is_some())
+            .unwrap();
+        let id = font.ttf().glyph_index('a').unwrap().0;
+        FrameItem::Text(TextItem {
+            font,
+            size: Abs::pt(10.0),
+            fill: Color::BLACK.into(),
+            stroke: None,
+            lang: Lang::ENGLISH,
+            region: None,
+            text: text.into(),
+            glyphs: vec![Glyph {
+                id,
+                x_advance: Em::one(),
+                x_offset: Em::new(0.0),
+                range: 0..text.len() as u16,
+                span: (Span::detached(), 0),
+            }],
+        })
+    }
+
+    /// A world with a single source file and the fonts from the development
+    /// assets.
+    struct TestWorld {
+        main: Source,
+        library: LazyHash<Library>,
+        book: LazyHash<FontBook>,
+        fonts: Vec<Font>,
+    }
+
+    impl TestWorld {
+        fn new(text: &str) -> Self {
+            Self::with_library(text, Library::default())
+        }
+
+        fn with_library(text: &str, library: Library) -> Self {
+            let fonts: Vec<_> = typst_dev_assets::fonts()
+                .flat_map(|data| Font::iter(Bytes::from_static(data)))
+                .collect();
+            Self {
+                main: Source::detached(text),
+                library: LazyHash::new(library),
+                book: LazyHash::new(FontBook::from_fonts(&fonts)),
+                fonts,
+            }
+        }
+
+        /// Lay out the source file.
+        fn compile(&self) -> PagedDocument {
+            typst::compile(self).output.unwrap()
+        }
+    }
+
+    impl World for TestWorld {
+        fn library(&self) -> &LazyHash<Library> {
+            &self.library
+        }
+
+        fn book(&self) -> &LazyHash<FontBook> {
+            &self.book
+        }
+
+        fn main(&self) -> FileId {
+            self.main.id()
+        }
+
+        fn source(&self, id: FileId) -> FileResult<Source> {
+            if id == self.main.id() {
+                Ok(self.main.clone())
+            } else {
+                Err(FileError::NotFound(id.vpath().as_rootless_path().into()))
+            }
+        }
+
+        fn file(&self, id: FileId) -> FileResult<Bytes> {
+            Err(FileError::NotFound(id.vpath().as_rootless_path().into()))
+        }
+
+        fn font(&self, index: usize) -> Option<Font> {
+            self.fonts.get(index).cloned()
+        }
+
+        fn today(&self, _: Option<i64>) -> Option<Datetime> {
+            None
+        }
+    }
+
+    /// Whether `haystack` contains `needle`.
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        count(haystack, needle) > 0
+    }
+
+    /// How often `haystack` contains `needle`.
+    fn count(haystack: &[u8], needle: &[u8]) -> usize {
+        haystack
+            .windows(needle.len())
+            .filter(|window| *window == needle)
+            .count()
+    }
+
+    /// The decompressed data of all deflated streams, such as the page
+    /// contents, in order.
+    fn inflated(pdf: &[u8]) -> Vec<u8> {
+        let find = |haystack: &[u8], needle: &[u8]| {
+            haystack.windows(needle.len()).position(|window| window == needle)
+        };
+
+        let mut data = vec![];
+        let mut rest = pdf;
+        while let Some(start) = find(rest, b"stream\n") {
+            rest = &rest[start + b"stream\n".len()..];
+            let Some(end) = find(rest, b"\nendstream") else { break };
+            if let Ok(stream) = miniz_oxide::inflate::decompress_to_vec_zlib(&rest[..end])
+            {
+                data.extend(stream);
+            }
+            rest = &rest[end + b"\nendstream".len()..];
+        }
+        data
+    }
+
+    #[test]
+    fn test_pdf_reproducible() {
+        // With a fixed timestamp, repeated exports must be byte-identical.
+        let document = document();
+        let datetime = Datetime::from_ymd_hms(2024, 12, 17, 10, 10, 10).unwrap();
+        let options = PdfOptions {
+            timestamp: Some(Timestamp::new_utc(datetime)),
+            ..Default::default()
+        };
+        let first = pdf(&document, &options).unwrap();
+        let second = pdf(&document, &options).unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_pdf_tagged() {
+        let document = document();
+        let untagged = pdf(&document, &PdfOptions::default()).unwrap();
+        assert!(!contains(&untagged, b"/StructTreeRoot"));
+
+        let options = PdfOptions { tagged: true, ..Default::default() };
+        let tagged = pdf(&document, &options).unwrap();
+        assert!(contains(&tagged, b"/StructTreeRoot"));
+        assert!(contains(&tagged, b"/MarkInfo"));
+        assert!(contains(&tagged, b"/StructParents"));
+    }
+
+    #[test]
+    fn test_pdf_tagged_structure() {
+        let mut heading = HeadingElem::new(TextElem::packed("Intro"))
+            .with_level(Smart::Custom(NonZeroUsize::new(2).unwrap()))
+            .pack();
+        heading.set_location(Location::new(1));
+        let data = typst_dev_assets::get("images/tiger.jpg").unwrap();
+        let image = Image::new(
+            Bytes::from_static(data),
+            ImageFormat::Raster(RasterFormat::Jpg),
+            Some("A tiger".into()),
+        )
+        .unwrap();
+
+        // A heading, a paragraph with two lines, another paragraph further
+        // down, and an image. The text is set in 10pt, so lines that are less
+        // than 10pt apart belong to the same paragraph.
+        let size = Size::splat(Abs::pt(100.0));
+        let mut frame = Frame::hard(size);
+        let at = |y| Point::with_y(Abs::pt(y));
+        let items = [
+            (at(10.0), FrameItem::Tag(Tag::Start(heading))),
+            (at(10.0), text("Intro")),
+            (at(10.0), FrameItem::Tag(Tag::End(Location::new(1), 0))),
+            (at(30.0), text("First ")),
+            (at(44.0), text("paragraph")),
+            (at(70.0), text("Second")),
+            (at(70.0), FrameItem::Image(image, size / 2.0, Span::detached())),
+        ];
+        for (pos, item) in items {
+            frame.push(pos, item);
+        }
+
+        let options = PdfOptions { tagged: true, ..Default::default() };
+        let pdf = pdf(&page(frame), &options).unwrap();
+
+        // Each heading, paragraph, and image forms its own structure element
+        // that refers to its marked content by MCID.
+        assert_eq!(count(&pdf, b"/S /H2"), 1);
+        assert_eq!(count(&pdf, b"/S /P"), 2);
+        assert_eq!(count(&pdf, b"/S /Figure"), 1);
+        assert!(contains(&pdf, b"/Alt (A tiger)"));
+        for mcids in [&b"/K [0]"[..], b"/K [1 2]", b"/K [3]", b"/K [4]"] {
+            assert!(contains(&pdf, mcids));
+        }
+
+        // All MCIDs of the page are listed in the parent tree.
+        assert!(contains(&pdf, b"/StructParents 0"));
+        assert!(contains(&pdf, b"/ParentTreeNextKey 1"));
+        assert_eq!(count(&pdf, b"/Nums [0 ["), 1);
+    }
+
+    #[test]
+    fn test_pdf_lang_spans() {
+        let world = TestWorld::new(
+            "#set page(width: auto, height: auto)\n\
+             Most of this text is English, #text(lang: \"de\")[etwas Deutsch], \
+             and then English again.",
+        );
+        let pdf = pdf(&world.compile(), &PdfOptions::default()).unwrap();
+        let content = inflated(&pdf);
+
+        // English is the document's main language, so only the German run is
+        // wrapped in a span with its language.
+        assert!(contains(&pdf, b"/Lang (en)"));
+        assert_eq!(count(&content, b"/Lang (de)"), 1);
+        assert_eq!(count(&content, b"/Lang"), 1);
+    }
+
+    #[test]
+    fn test_pdf_library_page_fill() {
+        let export = |library| {
+            let text = "#set page(width: 100pt, height: 100pt)\nHello";
+            let world = TestWorld::with_library(text, library);
+            inflated(&pdf(&world.compile(), &PdfOptions::default()).unwrap())
+        };
+
+        // Pages are transparent in PDF unless a fill is configured.
+        let background = b"0 0 100 100 re";
+        assert!(!contains(&export(Library::default()), background));
+
+        // The configured fill is painted across the whole page.
+        let red = Color::from_u8(255, 0, 0, 255);
+        let filled = export(Library::builder().with_page_fill(red).build());
+        assert!(contains(&filled, background));
+        assert!(contains(&filled, b"1 0 0 scn"));
+    }
+
+    /// A minimal 8x8 baseline JPEG with four (CMYK) components, optionally
+    /// with an Adobe APP14 marker segment.
+    fn cmyk_jpeg(adobe: bool) -> Vec<u8> {
+        let mut data = vec![0xFF, 0xD8];
+        if adobe {
+            data.extend([0xFF, 0xEE, 0x00, 0x0E]);
+            data.extend(b"Adobe");
+            data.extend([0x00, 0x64, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        }
+
+        // A quantization table of ones.
+        data.extend([0xFF, 0xDB, 0x00, 0x43, 0x00]);
+        data.extend([0x01; 64]);
+
+        // Frame header with four components sharing the quantization table.
+        data.extend([0xFF, 0xC0, 0x00, 0x14, 0x08, 0x00, 0x08, 0x00, 0x08, 0x04]);
+        for id in 1..=4 {
+            data.extend([id, 0x11, 0x00]);
+        }
+
+        // DC and AC Huffman tables with a single one-bit code for symbol 0.
+        for class in [0x00, 0x10] {
+            data.extend([0xFF, 0xC4, 0x00, 0x14, class, 0x01]);
+            data.extend([0x00; 16]);
+        }
+
+        // Scan header followed by one MCU in which every block has a zero DC
+        // difference and ends immediately.
+        data.extend([0xFF, 0xDA, 0x00, 0x0E, 0x04]);
+        for id in 1..=4 {
+            data.extend([id, 0x00]);
+        }
+        data.extend([0x00, 0x3F, 0x00, 0x00, 0xFF, 0xD9]);
+        data
+    }
+
+    #[test]
+    fn test_pdf_tagged_paragraphs() {
+        let world = TestWorld::new(
+            "#set page(width: 120pt, height: auto)\n\
+             = Intro\n\
+             The first paragraph is long enough to break across a few lines.\n\
+             \n\
+             The second paragraph, too, spans more than a single line.",
+        );
+        let options = PdfOptions { tagged: true, ..Default::default() };
+        let pdf = pdf(&world.compile(), &options).unwrap();
+
+        // The lines of each paragraph are grouped into one element, but the
+        // paragraphs stay apart.
+        assert_eq!(count(&pdf, b"/S /H1"), 1);
+        assert_eq!(count(&pdf, b"/S /P"), 2);
+    }
+
+    #[test]
+    fn test_pdf_cmyk_jpeg() {
+        let export = |adobe| {
+            let image = Image::new(
+                Bytes::from(cmyk_jpeg(adobe)),
+                ImageFormat::Raster(RasterFormat::Jpg),
+                None,
+            )
+            .unwrap();
+            let size = Size::splat(Abs::pt(100.0));
+            let mut frame = Frame::hard(size);
+            frame.push(Point::zero(), FrameItem::Image(image, size, Span::detached()));
+            pdf(&page(frame), &PdfOptions::default()).unwrap()
+        };
+
+        // CMYK JPEGs are embedded as-is instead of being converted to RGB.
+        let plain = export(false);
+        assert!(contains(&plain, b"/Filter /DCTDecode"));
+        assert!(contains(&plain, b"/ColorSpace /DeviceCMYK"));
+        assert!(!contains(&plain, b"/Decode"));
+
+        // Adobe JPEGs store inverted components, which the decode array
+        // flips back.
+        let adobe = export(true);
+        assert!(contains(&adobe, b"/Filter /DCTDecode"));
+        assert!(contains(&adobe, b"/ColorSpace /DeviceCMYK"));
+        assert!(contains(&adobe, b"/Decode [1 0 1 0 1 0 1 0]"));
+    }
+
+    #[test]
+    fn test_pdf_spot_color() {
+        let fallback = Color::Cmyk(Cmyk { c: 1.0, m: 0.5, y: 0.0, k: 0.0 });
+        let spot =
+            Spot::construct("Gold".into(), fallback, Ratio::new(0.5), true).unwrap();
+        let size = Size::splat(Abs::pt(100.0));
+        let mut frame = Frame::hard(size);
+        let shape = Geometry::Rect(size).filled(spot);
+        frame.push(Point::zero(), FrameItem::Shape(shape, Span::detached()));
+        let pdf = pdf(&page(frame), &PdfOptions::default()).unwrap();
+
+        // The ink is a separation whose tint transform interpolates from no
+        // ink to the full fallback color.
+        assert!(contains(&pdf, b"[/Separation /Gold /DeviceCMYK"));
+        assert!(contains(&pdf, b"/FunctionType 2"));
+        assert!(contains(&pdf, b"/Domain [0 1]"));
+        assert!(contains(&pdf, b"/C0 [0 0 0 0]"));
+        assert!(contains(&pdf, b"/C1 [1 0.5 0 0]"));
+        assert!(contains(&pdf, b"/N 1"));
+
+        // Only the fill overprints, but both flags are written because the
+        // fill one would otherwise default to the stroke one.
+        assert!(contains(&pdf, b"/OP false"));
+        assert!(contains(&pdf, b"/op true"));
+    }
+
+    #[test]
+    fn test_pdf_a_restricted_font() {
+        let FrameItem::Text(item) = text("a") else { unreachable!() };
+
+        // Mark the font as restricted-license by setting the embedding
+        // permissions in its OS/2 table to 2.
+        let mut data = item.font.data().to_vec();
+        let u32_at = |data: &[u8], at: usize| {
+            u32::from_be_bytes(data[at..at + 4].try_into().unwrap()) as usize
+        };
+        let dir = if data.starts_with(b"ttcf") {
+            u32_at(&data, 12 + 4 * item.font.index() as usize)
+        } else {
+            0
+        };
+        let count = usize::from(u16::from_be_bytes([data[dir + 4], data[dir + 5]]));
+        let record = (0..count)
+            .map(|i| dir + 12 + 16 * i)
+            .find(|&record| &data[record..record + 4] == b"OS/2")
+            .unwrap();
+        let os2 = u32_at(&data, record + 8);
+        data[os2 + 8..os2 + 10].copy_from_slice(&2u16.to_be_bytes());
+        let font = Font::new(Bytes::from(data), item.font.index()).unwrap();
+
+        let size = Size::splat(Abs::pt(100.0));
+        let mut frame = Frame::hard(size);
+        frame.push(Point::zero(), FrameItem::Text(TextItem { font, ..item }));
+        let document = page(frame);
+
+        // Without PDF/A, the font may be embedded.
+        assert!(pdf(&document, &PdfOptions::default()).is_ok());
+
+        let options = PdfOptions {
+            standards: PdfStandards::new(&[PdfStandard::A_2b]).unwrap(),
+            ..Default::default()
+        };
+        let errors = pdf(&document, &options).unwrap_err();
+        assert!(errors[0].message.contains("may not be embedded in PDF/A export"));
+    }
+
     #[test]
     fn test_timestamp_new_local() {
         let dummy_datetime = Datetime::from_ymd_hms(2024, 12, 17, 10, 10, 10).unwrap();
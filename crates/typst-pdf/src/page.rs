@@ -7,8 +7,9 @@ use pdf_writer::{Filter, Finish, Name, Rect, Ref, Str};
 use typst_library::diag::SourceResult;
 use typst_library::foundations::Label;
 use typst_library::introspection::Location;
-use typst_library::layout::{Abs, Page};
+use typst_library::layout::{Abs, Frame, FrameItem, Page};
 use typst_library::model::{Destination, Numbering};
+use typst_library::text::Lang;
 
 use crate::{
     content, AbsExt, PdfChunk, PdfOptions, Resources, WithDocument, WithRefs,
@@ -21,7 +22,10 @@ use crate::{
 pub fn traverse_pages(
     state: &WithDocument,
 ) -> SourceResult<(PdfChunk, (Vec<Option<EncodedPage>>, Resources<()>))> {
-    let mut resources = Resources::default();
+    let mut resources = Resources {
+        main_lang: main_language(state),
+        ..Resources::default()
+    };
     let mut pages = Vec::with_capacity(state.document.pages.len());
     let mut skipped_pages = 0;
     for (i, page) in state.document.pages.iter().enumerate() {
@@ -57,6 +61,39 @@ pub fn traverse_pages(
     Ok((PdfChunk::new(), (pages, resources)))
 }
 
+/// Determine the main language of the exported pages, that is, the language
+/// with the most glyphs.
+fn main_language(state: &WithDocument) -> Option<Lang> {
+    fn count(frame: &Frame, languages: &mut HashMap<Lang, usize>) {
+        for (_, item) in frame.items() {
+            match item {
+                FrameItem::Group(group) => count(&group.frame, languages),
+                FrameItem::Text(text) => {
+                    *languages.entry(text.lang).or_insert(0) += text.glyphs.len();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut languages = HashMap::new();
+    for (i, page) in state.document.pages.iter().enumerate() {
+        if !state
+            .options
+            .page_ranges
+            .as_ref()
+            .is_some_and(|ranges| !ranges.includes_page_index(i))
+        {
+            count(&page.frame, &mut languages);
+        }
+    }
+
+    languages
+        .into_iter()
+        .max_by_key(|&(lang, count)| (count, lang))
+        .map(|(lang, _)| lang)
+}
+
 /// Construct a page object.
 #[typst_macros::time(name = "construct page")]
 fn construct_page(
@@ -71,6 +108,7 @@ fn construct_page(
             &page.frame,
             page.fill_or_transparent(),
             None,
+            options.tagged,
         )?,
         label: None,
     })
@@ -199,6 +237,10 @@ fn write_page(
 
     page_writer.annotations(annotations);
 
+    if ctx.options.tagged {
+        page_writer.pair(Name(b"StructParents"), i as i32);
+    }
+
     page_writer.finish();
 
     chunk
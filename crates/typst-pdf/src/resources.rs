@@ -14,7 +14,6 @@ use pdf_writer::{Dict, Finish, Name, Ref};
 use subsetter::GlyphRemapper;
 use typst_library::diag::{SourceResult, StrResult};
 use typst_library::text::{Font, Lang};
-use typst_library::visualize::Image;
 use typst_syntax::Span;
 use typst_utils::Deferred;
 
@@ -22,7 +21,7 @@ use crate::color::ColorSpaces;
 use crate::color_font::ColorFontMap;
 use crate::extg::ExtGState;
 use crate::gradient::PdfGradient;
-use crate::image::EncodedImage;
+use crate::image::{EncodedImage, PdfImage};
 use crate::tiling::TilingRemapper;
 use crate::{PdfChunk, Renumber, WithEverything, WithResources};
 
@@ -61,7 +60,7 @@ pub struct Resources<R = Ref> {
     /// Deduplicates fonts used across the document.
     pub fonts: Remapper<Font>,
     /// Deduplicates images used across the document.
-    pub images: Remapper<Image>,
+    pub images: Remapper<PdfImage>,
     /// Handles to deferred image conversions.
     pub deferred_images: HashMap<usize, (Deferred<StrResult<EncodedImage>>, Span)>,
     /// Deduplicates gradients used across the document.
@@ -80,6 +79,9 @@ pub struct Resources<R = Ref> {
     /// We keep track of this to determine the main document language.
     /// BTreeMap is used to write sorted list of languages to metadata.
     pub languages: BTreeMap<Lang, usize>,
+    /// The main language of the document, determined before any content is
+    /// written. Text in other languages is tagged with its language.
+    pub main_lang: Option<Lang>,
 
     /// For each font a mapping from used glyphs to their text representation.
     /// This is used for the PDF's /ToUnicode map, and important for copy-paste
@@ -126,6 +128,7 @@ impl Default for Resources<()> {
             ext_gs: Remapper::new("Gs"),
             color_fonts: None,
             languages: BTreeMap::new(),
+            main_lang: None,
             glyph_sets: HashMap::new(),
             color_glyph_sets: HashMap::new(),
             glyph_remappers: HashMap::new(),
@@ -154,6 +157,7 @@ impl Resources<()> {
                 .zip(refs.color_fonts.as_ref())
                 .map(|(c, r)| Box::new(c.with_refs(r))),
             languages: self.languages,
+            main_lang: self.main_lang,
             glyph_sets: self.glyph_sets,
             color_glyph_sets: self.color_glyph_sets,
             glyph_remappers: self.glyph_remappers,
@@ -0,0 +1,205 @@
+//! Tagged PDF.
+//!
+//! When tagging is enabled, text and images in page content streams are
+//! wrapped in marked content with a marked-content identifier (MCID). The
+//! structure tree written here groups this marked content into structure
+//! elements and links them to the pages they appear on.
+
+use std::num::NonZeroUsize;
+
+use ecow::EcoString;
+use pdf_writer::{Finish, Name, Pdf, Ref, TextStr};
+use typst_library::introspection::Location;
+use typst_library::layout::Abs;
+
+use crate::WithEverything;
+
+/// A piece of marked content in a page's content stream.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkedContent {
+    /// The marked-content identifier, unique within the page.
+    pub mcid: i32,
+    /// The structure element the content belongs to.
+    pub kind: StructKind,
+}
+
+/// The kind of structure element that marked content belongs to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StructKind {
+    /// Text of the heading with the given location and level.
+    Heading(Location, NonZeroUsize),
+    /// Body text with the given extent.
+    Paragraph(TextExtent),
+    /// An image with optional alternative text.
+    Figure(Option<EcoString>),
+}
+
+impl StructKind {
+    /// The standard structure type of this kind.
+    pub fn role(&self) -> Name<'static> {
+        match self {
+            Self::Heading(_, level) => Name(match level.get() {
+                1 => b"H1",
+                2 => b"H2",
+                3 => b"H3",
+                4 => b"H4",
+                5 => b"H5",
+                _ => b"H6",
+            }),
+            Self::Paragraph(_) => Name(b"P"),
+            Self::Figure(_) => Name(b"Figure"),
+        }
+    }
+}
+
+/// The vertical extent of a text run on its page.
+///
+/// Paragraphs are not visible in frames, so the structure tree derives them
+/// from the position of their text instead.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TextExtent {
+    /// The top of the run's capital letters, measured downwards from the top
+    /// of the page.
+    pub top: Abs,
+    /// The run's baseline, measured downwards from the top of the page.
+    pub bottom: Abs,
+    /// The font size of the run.
+    pub size: Abs,
+}
+
+impl TextExtent {
+    /// Whether this run continues the paragraph whose current line has the
+    /// given extent.
+    ///
+    /// A run continues the paragraph if it sits on the same line or on a line
+    /// less than an em below it. This separates lines with the default
+    /// leading of `0.65em` from paragraphs with the default spacing of
+    /// `1.2em`. Text further apart or further up, for instance in the next
+    /// column, starts a new paragraph.
+    fn continues(&self, line: &Self) -> bool {
+        let same_line = self.top < line.bottom && self.bottom > line.top;
+        let gap = self.top - line.bottom;
+        same_line || (gap >= Abs::zero() && gap < self.size.max(line.size))
+    }
+
+    /// The extent of the line after this run was added to it.
+    fn extend(&self, line: &Self) -> Self {
+        if self.top < line.bottom && self.bottom > line.top {
+            Self {
+                top: self.top.min(line.top),
+                bottom: self.bottom.max(line.bottom),
+                size: self.size.max(line.size),
+            }
+        } else {
+            *self
+        }
+    }
+}
+
+/// Determine how many of the given pieces of marked content form the
+/// structure element of the first one.
+fn element_len(marked: &[MarkedContent]) -> usize {
+    let rest = marked[1..].iter().map(|next| &next.kind);
+    1 + match &marked[0].kind {
+        StructKind::Heading(loc, _) => rest
+            .take_while(
+                |kind| matches!(kind, StructKind::Heading(other, _) if other == loc),
+            )
+            .count(),
+        StructKind::Paragraph(first) => {
+            let mut line = *first;
+            rest.map_while(|kind| match kind {
+                StructKind::Paragraph(run) if run.continues(&line) => {
+                    line = run.extend(&line);
+                    Some(())
+                }
+                _ => None,
+            })
+            .count()
+        }
+        StructKind::Figure(_) => 0,
+    }
+}
+
+/// Write the structure tree.
+///
+/// Consecutive marked content of the same heading on a page forms one
+/// structure element, as does consecutive body text that [continues] a
+/// paragraph. Each image forms its own element. Returns the reference of the
+/// structure tree root, or `None` if tagging is disabled.
+///
+/// [continues]: TextExtent::continues
+pub fn write_structure_tree(
+    pdf: &mut Pdf,
+    alloc: &mut Ref,
+    ctx: &WithEverything,
+) -> Option<Ref> {
+    if !ctx.options.tagged {
+        return None;
+    }
+
+    let root_ref = alloc.bump();
+    let document_ref = alloc.bump();
+
+    let mut elements = vec![];
+    let mut parent_tree = vec![];
+    for (i, (page, page_ref)) in ctx.pages.iter().zip(&ctx.globals.pages).enumerate() {
+        let Some((page, &page_ref)) = page.as_ref().zip(page_ref.as_ref()) else {
+            continue;
+        };
+
+        // For each MCID on this page, the structure element it belongs to.
+        let mut parents = vec![];
+        let mut start = 0;
+        let marked = &page.content.marked;
+        while start < marked.len() {
+            let kind = &marked[start].kind;
+            let end = start + element_len(&marked[start..]);
+
+            let element_ref = alloc.bump();
+            let mut element = pdf.indirect(element_ref).dict();
+            element.pair(Name(b"Type"), Name(b"StructElem"));
+            element.pair(Name(b"S"), kind.role());
+            element.pair(Name(b"P"), document_ref);
+            element.pair(Name(b"Pg"), page_ref);
+            if let StructKind::Figure(Some(alt)) = kind {
+                element.pair(Name(b"Alt"), TextStr(alt.as_str()));
+            }
+            element
+                .insert(Name(b"K"))
+                .array()
+                .items(marked[start..end].iter().map(|m| m.mcid));
+            element.finish();
+
+            elements.push(element_ref);
+            parents.extend(std::iter::repeat(element_ref).take(end - start));
+            start = end;
+        }
+
+        parent_tree.push((i as i32, parents));
+    }
+
+    let mut document = pdf.indirect(document_ref).dict();
+    document.pair(Name(b"Type"), Name(b"StructElem"));
+    document.pair(Name(b"S"), Name(b"Document"));
+    document.pair(Name(b"P"), root_ref);
+    document.insert(Name(b"K")).array().items(elements);
+    document.finish();
+
+    let mut root = pdf.indirect(root_ref).dict();
+    root.pair(Name(b"Type"), Name(b"StructTreeRoot"));
+    root.pair(Name(b"K"), document_ref);
+    let next_key = parent_tree.last().map_or(0, |(i, _)| i + 1);
+    let mut tree = root.insert(Name(b"ParentTree")).dict();
+    let mut nums = tree.insert(Name(b"Nums")).array();
+    for (i, parents) in parent_tree {
+        nums.item(i);
+        nums.push().array().items(parents);
+    }
+    nums.finish();
+    tree.finish();
+    root.pair(Name(b"ParentTreeNextKey"), next_key);
+    root.finish();
+
+    Some(root_ref)
+}
@@ -0,0 +1,202 @@
+//! Overlays of layout guides for debugging.
+
+use tiny_skia as sk;
+use typst_library::layout::{Abs, Frame, FrameItem, FrameKind, Point, Size};
+
+use crate::{to_sk_transform, AbsExt};
+
+/// The color of hard frame bounds.
+const HARD_FRAME: [u8; 4] = [0, 116, 217, 200];
+/// The color of soft frame bounds.
+const SOFT_FRAME: [u8; 4] = [177, 13, 201, 120];
+/// The color of frame and text baselines.
+const BASELINE: [u8; 4] = [255, 65, 54, 200];
+/// The color of image and link bounds.
+const BOUNDS: [u8; 4] = [46, 204, 64, 200];
+/// The color of the content extent within hard frames.
+const PADDING: [u8; 4] = [255, 133, 27, 200];
+/// The color of the regions a page was laid out in.
+const REGION: [u8; 4] = [17, 17, 17, 160];
+
+/// Draw the regions of a page on top of an existing rendering.
+///
+/// The page's body and its marginals are each laid out into a region of the
+/// page and end up as hard frames directly within the page frame. This
+/// outlines them with dashed lines.
+pub fn render_regions(canvas: &mut sk::Pixmap, ts: sk::Transform, page: &Frame) {
+    for (pos, item) in page.items() {
+        let FrameItem::Group(group) = item else { continue };
+        if group.frame.kind() != FrameKind::Hard {
+            continue;
+        }
+
+        let ts = ts
+            .pre_translate(pos.x.to_f32(), pos.y.to_f32())
+            .pre_concat(to_sk_transform(&group.transform));
+        let Some(rect) = to_sk_rect(Point::zero(), group.frame.size()) else {
+            continue;
+        };
+
+        let dash = sk::StrokeDash::new(vec![4.0, 2.0], 0.0);
+        stroke_path(canvas, ts, sk::PathBuilder::from_rect(rect), REGION, dash);
+    }
+}
+
+/// Draw the layout guides of a frame on top of an existing rendering.
+///
+/// This outlines hard frames in blue and soft frames in purple, draws the
+/// baselines of frames and text runs in red and outlines images and links in
+/// green. Within hard frames, the extent of the contents is outlined in
+/// orange, so that padding shows up as the gap to the blue outline.
+pub fn render_guides(canvas: &mut sk::Pixmap, ts: sk::Transform, frame: &Frame) {
+    let color = match frame.kind() {
+        FrameKind::Hard => HARD_FRAME,
+        FrameKind::Soft => SOFT_FRAME,
+    };
+
+    stroke_rect(canvas, ts, frame.size(), color);
+    if frame.kind() == FrameKind::Hard {
+        if let Some((min, max)) = content_extent(frame) {
+            if min != Point::zero() || max != frame.size().to_point() {
+                if let Some(rect) = to_sk_rect(min, (max - min).to_size()) {
+                    let path = sk::PathBuilder::from_rect(rect);
+                    stroke_path(canvas, ts, path, PADDING, None);
+                }
+            }
+        }
+    }
+
+    if frame.has_baseline() {
+        stroke_line(canvas, ts, frame.baseline(), frame.width(), BASELINE);
+    }
+
+    for (pos, item) in frame.items() {
+        let ts = ts.pre_translate(pos.x.to_f32(), pos.y.to_f32());
+        match item {
+            FrameItem::Group(group) => {
+                let ts = ts.pre_concat(to_sk_transform(&group.transform));
+                render_guides(canvas, ts, &group.frame);
+            }
+            FrameItem::Text(text) => {
+                stroke_line(canvas, ts, Abs::zero(), text.width(), BASELINE);
+            }
+            FrameItem::Image(_, size, _) | FrameItem::Link(_, size) => {
+                stroke_rect(canvas, ts, *size, BOUNDS);
+            }
+            FrameItem::Shape(_, _) | FrameItem::Tag(_) => {}
+        }
+    }
+}
+
+/// The smallest rectangle enclosing the items of a frame, as its top-left and
+/// bottom-right corners.
+///
+/// Transformed groups and tags are not taken into account.
+fn content_extent(frame: &Frame) -> Option<(Point, Point)> {
+    let mut extent: Option<(Point, Point)> = None;
+    for (pos, item) in frame.items() {
+        let (min, max) = match item {
+            FrameItem::Group(group) if group.transform.is_identity() => {
+                (*pos, *pos + group.frame.size().to_point())
+            }
+            FrameItem::Text(text) => {
+                let metrics = text.font.metrics();
+                let top = pos.y - metrics.ascender.at(text.size);
+                let bottom = pos.y - metrics.descender.at(text.size);
+                (Point::new(pos.x, top), Point::new(pos.x + text.width(), bottom))
+            }
+            FrameItem::Shape(shape, _) => {
+                (*pos, *pos + shape.geometry.bbox_size().to_point())
+            }
+            FrameItem::Image(_, size, _) | FrameItem::Link(_, size) => {
+                (*pos, *pos + size.to_point())
+            }
+            FrameItem::Group(_) | FrameItem::Tag(_) => continue,
+        };
+
+        extent = Some(match extent {
+            Some((lo, hi)) => (lo.min(min), hi.max(max)),
+            None => (min, max),
+        });
+    }
+    extent
+}
+
+/// Convert a position and size into a rectangle, if it is not degenerate.
+fn to_sk_rect(pos: Point, size: Size) -> Option<sk::Rect> {
+    sk::Rect::from_xywh(pos.x.to_f32(), pos.y.to_f32(), size.x.to_f32(), size.y.to_f32())
+}
+
+/// Stroke the outline of a rectangle with the given size.
+fn stroke_rect(canvas: &mut sk::Pixmap, ts: sk::Transform, size: Size, color: [u8; 4]) {
+    let Some(rect) = to_sk_rect(Point::zero(), size) else { return };
+    stroke_path(canvas, ts, sk::PathBuilder::from_rect(rect), color, None);
+}
+
+/// Stroke a horizontal line at the given vertical offset.
+fn stroke_line(
+    canvas: &mut sk::Pixmap,
+    ts: sk::Transform,
+    y: Abs,
+    length: Abs,
+    color: [u8; 4],
+) {
+    let mut builder = sk::PathBuilder::new();
+    builder.move_to(0.0, y.to_f32());
+    builder.line_to(length.to_f32(), y.to_f32());
+    if let Some(path) = builder.finish() {
+        stroke_path(canvas, ts, path, color, None);
+    }
+}
+
+/// Stroke a path with a hairline of the given color, optionally dashed.
+fn stroke_path(
+    canvas: &mut sk::Pixmap,
+    ts: sk::Transform,
+    path: sk::Path,
+    color: [u8; 4],
+    dash: Option<sk::StrokeDash>,
+) {
+    let [r, g, b, a] = color;
+    let mut paint = sk::Paint::default();
+    paint.set_color_rgba8(r, g, b, a);
+    paint.anti_alias = true;
+    // A zero width results in a hairline that is one pixel wide at any scale.
+    let stroke = sk::Stroke { width: 0.0, dash, ..Default::default() };
+    canvas.stroke_path(&path, &paint, &stroke, ts, None);
+}
+
+#[cfg(test)]
+mod tests {
+    use typst_library::foundations::{Content, Smart};
+    use typst_library::layout::Page;
+    use typst_library::visualize::{Color, Geometry};
+    use typst_syntax::Span;
+
+    use super::*;
+
+    #[test]
+    fn test_render_debug() {
+        // A page with a body region that contains a padded rectangle.
+        let mut inner = Frame::hard(Size::new(Abs::pt(60.0), Abs::pt(40.0)));
+        let shape = Geometry::Rect(Size::splat(Abs::pt(20.0))).filled(Color::BLACK);
+        inner.push(
+            Point::new(Abs::pt(10.0), Abs::pt(10.0)),
+            FrameItem::Shape(shape, Span::detached()),
+        );
+        let mut frame = Frame::hard(Size::new(Abs::pt(80.0), Abs::pt(60.0)));
+        frame.push_frame(Point::new(Abs::pt(10.0), Abs::pt(10.0)), inner);
+        let page = Page {
+            frame,
+            fill: Smart::Auto,
+            numbering: None,
+            supplement: Content::empty(),
+            number: 1,
+        };
+
+        let plain = crate::render(&page, 2.0);
+        let debug = crate::render_debug(&page, 2.0);
+        assert_eq!((debug.width(), debug.height()), (160, 120));
+        assert_ne!(plain.data(), debug.data());
+    }
+}
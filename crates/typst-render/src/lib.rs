@@ -1,5 +1,6 @@
 //! Rendering of Typst documents into raster images.
 
+mod debug;
 mod image;
 mod paint;
 mod shape;
@@ -41,6 +42,20 @@ pub fn render(page: &Page, pixel_per_pt: f32) -> sk::Pixmap {
     canvas
 }
 
+/// Export a page into a raster image with layout guides drawn on top.
+///
+/// This is a tool for diagnosing layout issues: In addition to the normal
+/// rendering, it outlines the regions of the page, all frames and their
+/// contents, images, and links and draws the baselines of frames and text
+/// runs. The normal [`render`] output is not affected.
+pub fn render_debug(page: &Page, pixel_per_pt: f32) -> sk::Pixmap {
+    let mut canvas = render(page, pixel_per_pt);
+    let ts = sk::Transform::from_scale(pixel_per_pt, pixel_per_pt);
+    debug::render_regions(&mut canvas, ts, &page.frame);
+    debug::render_guides(&mut canvas, ts, &page.frame);
+    canvas
+}
+
 /// Export a document with potentially multiple pages into a single raster image.
 pub fn render_merged(
     document: &PagedDocument,
@@ -172,6 +172,10 @@ pub fn to_sk_paint<'a>(
             sk_paint.set_color(to_sk_color(*color));
             sk_paint.anti_alias = true;
         }
+        Paint::Spot(spot) => {
+            sk_paint.set_color(to_sk_color(spot.to_color()));
+            sk_paint.anti_alias = true;
+        }
         Paint::Gradient(gradient) => {
             let relative = gradient.unwrap_relative(on_text);
             let container_size = match relative {
@@ -145,6 +145,14 @@ fn render_outline_glyph(
                 paint::to_sk_color_u8(*color).premultiply(),
             )?;
         }
+        Paint::Spot(spot) => {
+            write_bitmap(
+                canvas,
+                &bitmap,
+                &state,
+                paint::to_sk_color_u8(spot.to_color()).premultiply(),
+            )?;
+        }
         Paint::Tiling(tiling) => {
             let pixmap = paint::render_tiling_frame(&state, tiling);
             let sampler = TilingSampler::new(tiling, &pixmap, &state, true);
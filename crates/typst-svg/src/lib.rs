@@ -1,4 +1,15 @@
 //! Rendering of Typst documents into SVG images.
+//!
+//! Each page or frame becomes one self-contained `<svg>` element. Text is not
+//! emitted as `<text>`: every glyph is converted to an outline (or an embedded
+//! image for bitmap and SVG glyphs), defined once as a `<symbol>`, and placed
+//! with `<use>`. This makes the output independent of the fonts installed on
+//! the viewer's system, at the cost of the text not being selectable.
+//!
+//! Transforms of groups, such as those from `rotate` and `scale`, map to
+//! `transform` attributes. Groups that clip their contents, including boxes
+//! and blocks with a corner radius, reference a `<clipPath>` built from the
+//! clip outline.
 
 mod image;
 mod paint;
@@ -21,7 +32,7 @@ use xmlwriter::XmlWriter;
 use crate::paint::{GradientRef, SVGSubGradient, TilingRef};
 use crate::text::RenderedGlyph;
 
-/// Export a frame into a SVG file.
+/// Export a page into a SVG file.
 #[typst_macros::time(name = "svg")]
 pub fn svg(page: &Page) -> String {
     let mut renderer = SVGRenderer::new();
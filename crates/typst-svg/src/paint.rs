@@ -40,6 +40,9 @@ impl SVGRenderer {
     ) {
         match fill {
             Paint::Solid(color) => self.xml.write_attribute("fill", &color.encode()),
+            Paint::Spot(spot) => {
+                self.xml.write_attribute("fill", &spot.to_color().encode())
+            }
             Paint::Gradient(gradient) => {
                 let id = self.push_gradient(gradient, size, ts);
                 self.xml.write_attribute_fmt("fill", format_args!("url(#{id})"));
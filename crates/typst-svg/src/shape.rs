@@ -108,6 +108,9 @@ impl SVGRenderer {
     ) {
         match &stroke.paint {
             Paint::Solid(color) => self.xml.write_attribute("stroke", &color.encode()),
+            Paint::Spot(spot) => {
+                self.xml.write_attribute("stroke", &spot.to_color().encode())
+            }
             Paint::Gradient(gradient) => {
                 let id = self.push_gradient(gradient, size, fill_transform);
                 self.xml.write_attribute_fmt("stroke", format_args!("url(#{id})"));
@@ -156,7 +156,7 @@ impl SVGRenderer {
 
     fn text_paint_transform(&self, state: State, paint: &Paint) -> Transform {
         match paint {
-            Paint::Solid(_) => Transform::identity(),
+            Paint::Solid(_) | Paint::Spot(_) => Transform::identity(),
             Paint::Gradient(gradient) => match gradient.unwrap_relative(true) {
                 RelativeTo::Self_ => Transform::identity(),
                 RelativeTo::Parent => Transform::scale(
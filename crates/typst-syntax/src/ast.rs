@@ -191,6 +191,8 @@ pub enum Expr<'a> {
     While(WhileLoop<'a>),
     /// A for loop: `for x in y { z }`.
     For(ForLoop<'a>),
+    /// A match expression: `match x { 1: a, _: b }`.
+    Match(MatchExpr<'a>),
     /// A module import: `import "utils.typ": a, b, c`.
     Import(ModuleImport<'a>),
     /// A module include: `include "chapter1.typ"`.
@@ -267,6 +269,7 @@ impl<'a> AstNode<'a> for Expr<'a> {
             SyntaxKind::Conditional => node.cast().map(Self::Conditional),
             SyntaxKind::WhileLoop => node.cast().map(Self::While),
             SyntaxKind::ForLoop => node.cast().map(Self::For),
+            SyntaxKind::MatchExpr => node.cast().map(Self::Match),
             SyntaxKind::ModuleImport => node.cast().map(Self::Import),
             SyntaxKind::ModuleInclude => node.cast().map(Self::Include),
             SyntaxKind::LoopBreak => node.cast().map(Self::Break),
@@ -331,6 +334,7 @@ impl<'a> AstNode<'a> for Expr<'a> {
             Self::Conditional(v) => v.to_untyped(),
             Self::While(v) => v.to_untyped(),
             Self::For(v) => v.to_untyped(),
+            Self::Match(v) => v.to_untyped(),
             Self::Import(v) => v.to_untyped(),
             Self::Include(v) => v.to_untyped(),
             Self::Break(v) => v.to_untyped(),
@@ -2012,6 +2016,72 @@ impl<'a> ForLoop<'a> {
     }
 }
 
+node! {
+    /// A match expression: `match x { 1: a, _: b }`.
+    MatchExpr
+}
+
+impl<'a> MatchExpr<'a> {
+    /// The value to match against.
+    pub fn scrutinee(self) -> Expr<'a> {
+        self.0.cast_first_match().unwrap_or_default()
+    }
+
+    /// The arms, in the order they are tried.
+    pub fn arms(self) -> impl DoubleEndedIterator<Item = MatchArm<'a>> {
+        self.0.children().filter_map(SyntaxNode::cast)
+    }
+}
+
+node! {
+    /// An arm of a match expression: `let (a, b) if a > b: a`.
+    MatchArm
+}
+
+impl<'a> MatchArm<'a> {
+    /// The pattern the value is checked against.
+    pub fn pattern(self) -> MatchPattern<'a> {
+        let mut children = self.0.children();
+        match children.next().map(SyntaxNode::kind) {
+            Some(SyntaxKind::Underscore) => MatchPattern::Wildcard,
+            Some(SyntaxKind::Let) => MatchPattern::Binding(
+                children.find_map(SyntaxNode::cast).unwrap_or_default(),
+            ),
+            _ => MatchPattern::Value(self.0.cast_first_match().unwrap_or_default()),
+        }
+    }
+
+    /// An additional condition that must hold for the arm to be taken:
+    /// `if x > 0`.
+    pub fn guard(self) -> Option<Expr<'a>> {
+        let mut children =
+            self.0.children().take_while(|&c| c.kind() != SyntaxKind::Colon);
+        children.find(|c| c.kind() == SyntaxKind::If)?;
+        children.find_map(SyntaxNode::cast)
+    }
+
+    /// The expression to evaluate if the arm is taken.
+    pub fn body(self) -> Expr<'a> {
+        self.0
+            .children()
+            .skip_while(|&c| c.kind() != SyntaxKind::Colon)
+            .find_map(SyntaxNode::cast)
+            .unwrap_or_default()
+    }
+}
+
+/// The pattern of a match arm.
+#[derive(Debug, Copy, Clone, Hash)]
+pub enum MatchPattern<'a> {
+    /// Matches any value: `_`.
+    Wildcard,
+    /// Matches values with the shape of the pattern and binds its variables:
+    /// `let (x, ..rest)`.
+    Binding(Pattern<'a>),
+    /// Matches values equal to the result of the expression: `"a"`.
+    Value(Expr<'a>),
+}
+
 node! {
     /// A module import: `import "utils.typ": a, b, c`.
     ModuleImport
@@ -201,6 +201,12 @@ pub enum SyntaxKind {
     Include,
     /// The `as` keyword.
     As,
+    /// The `match` keyword.
+    ///
+    /// This is a contextual keyword: It is only produced by the parser when
+    /// `match` starts a match expression and is lexed as an identifier
+    /// otherwise.
+    Match,
 
     /// The contents of a code block.
     Code,
@@ -260,6 +266,10 @@ pub enum SyntaxKind {
     WhileLoop,
     /// A for loop: `for x in y { z }`.
     ForLoop,
+    /// A match expression: `match x { 1: a, _: b }`.
+    MatchExpr,
+    /// An arm of a match expression: `let (a, b) if a > b: a`.
+    MatchArm,
     /// A module import: `import "utils.typ": a, b, c`.
     ModuleImport,
     /// Items to import from a module: `a, b, c`.
@@ -349,6 +359,7 @@ impl SyntaxKind {
                 | Self::Import
                 | Self::Include
                 | Self::As
+                | Self::Match
         )
     }
 
@@ -462,6 +473,7 @@ impl SyntaxKind {
             Self::Import => "keyword `import`",
             Self::Include => "keyword `include`",
             Self::As => "keyword `as`",
+            Self::Match => "keyword `match`",
             Self::Code => "code",
             Self::Ident => "identifier",
             Self::Bool => "boolean",
@@ -491,6 +503,8 @@ impl SyntaxKind {
             Self::Conditional => "`if` expression",
             Self::WhileLoop => "while-loop expression",
             Self::ForLoop => "for-loop expression",
+            Self::MatchExpr => "`match` expression",
+            Self::MatchArm => "match arm",
             Self::ModuleImport => "`import` expression",
             Self::ImportItems => "import items",
             Self::ImportItemPath => "imported item path",
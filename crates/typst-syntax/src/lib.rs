@@ -25,7 +25,7 @@ pub use self::lexer::{
 pub use self::node::{LinkedChildren, LinkedNode, Side, SyntaxError, SyntaxNode};
 pub use self::parser::{parse, parse_code, parse_math};
 pub use self::path::VirtualPath;
-pub use self::source::Source;
+pub use self::source::{diff, Source};
 pub use self::span::{Span, Spanned};
 
 use self::lexer::{LexMode, Lexer};
@@ -696,6 +696,7 @@ fn code_expr_prec(p: &mut Parser, atomic: bool, min_prec: usize) {
 fn code_primary(p: &mut Parser, atomic: bool) {
     let m = p.marker();
     match p.current() {
+        SyntaxKind::Ident if at_match_expr(p) => match_expr(p),
         SyntaxKind::Ident => {
             p.eat();
             if !atomic && p.at(SyntaxKind::Arrow) {
@@ -917,6 +918,91 @@ fn for_loop(p: &mut Parser) {
     p.wrap(m, SyntaxKind::ForLoop);
 }
 
+/// Whether the current identifier starts a match expression.
+///
+/// `match` is not reserved as a keyword so that it can still be used as a
+/// variable or field name. It only starts a match expression if it is followed
+/// by whitespace (but no newline) and something that can start the scrutinee.
+/// Blocks are excluded so that expressions like `for x in match { .. }` keep
+/// working. Like other control flow, a match expression may also directly
+/// follow a hash in markup.
+fn at_match_expr(p: &Parser) -> bool {
+    if p.current_text() != "match" {
+        return false;
+    }
+
+    let mut lexer = p.lexer.clone();
+    let (mut kind, _) = lexer.next();
+    if !kind.is_trivia() {
+        return false;
+    }
+
+    while kind.is_trivia() {
+        if lexer.newline() {
+            return false;
+        }
+        (kind, _) = lexer.next();
+    }
+
+    set::ATOMIC_CODE_EXPR.contains(kind)
+        && !matches!(kind, SyntaxKind::LeftBrace | SyntaxKind::LeftBracket)
+}
+
+/// Parses a match expression: `match x { 1: a, _: b }`.
+fn match_expr(p: &mut Parser) {
+    let m = p.marker();
+    p.convert_and_eat(SyntaxKind::Match);
+    code_expr(p);
+
+    if !p.at(SyntaxKind::LeftBrace) {
+        p.expected("match arms");
+        p.wrap(m, SyntaxKind::MatchExpr);
+        return;
+    }
+
+    let m2 = p.marker();
+    p.enter_modes(LexMode::Code, AtNewline::Continue, |p| {
+        p.assert(SyntaxKind::LeftBrace);
+        while !p.current().is_terminator() {
+            if !p.at_set(set::CODE_EXPR) {
+                p.unexpected();
+                continue;
+            }
+
+            match_arm(p);
+
+            if !p.current().is_terminator() {
+                p.expect(SyntaxKind::Comma);
+            }
+        }
+        p.expect_closing_delimiter(m2, SyntaxKind::RightBrace);
+    });
+    p.wrap(m, SyntaxKind::MatchExpr);
+}
+
+/// Parses a match arm: `let (a, b) if a > b: a`.
+fn match_arm(p: &mut Parser) {
+    let m = p.marker();
+    match p.current() {
+        SyntaxKind::Underscore => p.eat(),
+        SyntaxKind::Let => {
+            p.eat();
+            pattern(p, false, &mut HashSet::new(), None);
+        }
+        _ => code_expr(p),
+    }
+
+    if p.eat_if(SyntaxKind::If) {
+        code_expr(p);
+    }
+
+    if p.expect(SyntaxKind::Colon) {
+        code_expr(p);
+    }
+
+    p.wrap(m, SyntaxKind::MatchArm);
+}
+
 /// Parses a module import: `import "utils.typ": a, b, c`.
 fn module_import(p: &mut Parser) {
     let m = p.marker();
@@ -70,39 +70,17 @@ impl Source {
 
     /// Fully replace the source text.
     ///
-    /// This performs a naive (suffix/prefix-based) diff of the old and new text
-    /// to produce the smallest single edit that transforms old into new and
-    /// then calls [`edit`](Self::edit) with it.
+    /// This performs a naive (suffix/prefix-based) [`diff`] of the old and new
+    /// text to produce the smallest single edit that transforms old into new
+    /// and then calls [`edit`](Self::edit) with it.
     ///
     /// Returns the range in the new source that was ultimately reparsed.
     pub fn replace(&mut self, new: &str) -> Range<usize> {
         let _scope = typst_timing::TimingScope::new("replace source");
-        let old = self.text();
-
-        let mut prefix =
-            zip(old.bytes(), new.bytes()).take_while(|(x, y)| x == y).count();
-
-        if prefix == old.len() && prefix == new.len() {
-            return 0..0;
-        }
-
-        while !old.is_char_boundary(prefix) || !new.is_char_boundary(prefix) {
-            prefix -= 1;
-        }
-
-        let mut suffix = zip(old[prefix..].bytes().rev(), new[prefix..].bytes().rev())
-            .take_while(|(x, y)| x == y)
-            .count();
-
-        while !old.is_char_boundary(old.len() - suffix)
-            || !new.is_char_boundary(new.len() - suffix)
-        {
-            suffix += 1;
+        match diff(self.text(), new) {
+            Some((replace, with)) => self.edit(replace, with),
+            None => 0..0,
         }
-
-        let replace = prefix..old.len() - suffix;
-        let with = &new[prefix..new.len() - suffix];
-        self.edit(replace, with)
     }
 
     /// Edit the source file by replacing the given range.
@@ -272,6 +250,36 @@ impl AsRef<str> for Source {
     }
 }
 
+/// Compute the smallest single edit that transforms `old` into `new`.
+///
+/// Returns the byte range in `old` that must be replaced and the text in `new`
+/// to replace it with, or `None` if the texts are identical. The edit is found
+/// by trimming the common prefix and suffix, so it may be larger than
+/// necessary if the texts differ in multiple places.
+pub fn diff<'a>(old: &str, new: &'a str) -> Option<(Range<usize>, &'a str)> {
+    let mut prefix = zip(old.bytes(), new.bytes()).take_while(|(x, y)| x == y).count();
+
+    if prefix == old.len() && prefix == new.len() {
+        return None;
+    }
+
+    while !old.is_char_boundary(prefix) || !new.is_char_boundary(prefix) {
+        prefix -= 1;
+    }
+
+    let mut suffix = zip(old[prefix..].bytes().rev(), new[prefix..].bytes().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+
+    while !old.is_char_boundary(old.len() - suffix)
+        || !new.is_char_boundary(new.len() - suffix)
+    {
+        suffix += 1;
+    }
+
+    Some((prefix..old.len() - suffix, &new[prefix..new.len() - suffix]))
+}
+
 /// Metadata about a line.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 struct Line {
@@ -324,6 +332,7 @@ fn len_utf16(string: &str) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Side, SyntaxKind};
 
     const TEST: &str = "ä\tcde\nf💛g\r\nhi\rjkl";
 
@@ -443,4 +452,43 @@ mod tests {
         // Test removing everything.
         test(TEST, 0..21, "", "");
     }
+
+    #[test]
+    fn test_source_diff() {
+        assert_eq!(diff("abc", "abc"), None);
+        assert_eq!(diff("abc", "abxc"), Some((2..2, "x")));
+        assert_eq!(diff("abxc", "abc"), Some((2..3, "")));
+        assert_eq!(diff("a bc d", "a xyz d"), Some((2..4, "xyz")));
+        assert_eq!(diff("", "new"), Some((0..0, "new")));
+
+        // Edits never split a character.
+        assert_eq!(diff("a💛b", "a💚b"), Some((1..5, "💚")));
+    }
+
+    #[test]
+    fn test_source_file_spans() {
+        // Every node, including operators and individual arguments, must have
+        // a span that resolves back to exactly its own range.
+        fn check(source: &Source, node: &LinkedNode) {
+            assert!(!node.span().is_detached());
+            assert_eq!(source.range(node.span()), Some(node.range()));
+            for child in node.children() {
+                check(source, &child);
+            }
+        }
+
+        let text = "#let f(x, y: 2) = (x + -y) * calc.pow(x, 2)\n#f(1, y: (3, {4}))";
+        let source = Source::detached(text);
+        check(&source, &LinkedNode::new(source.root()));
+
+        // Find the innermost expression and compare with its source slice.
+        let start = text.find("{4}").unwrap() + 1;
+        let root = LinkedNode::new(source.root());
+        let leaf = root.leaf_at(start + 1, Side::Before).unwrap();
+        let range = source.range(leaf.span()).unwrap();
+        assert_eq!(&text[range], "4");
+        let block = leaf.parent().and_then(|code| code.parent()).unwrap();
+        assert_eq!(block.kind(), SyntaxKind::CodeBlock);
+        assert_eq!(&text[source.range(block.span()).unwrap()], "{4}");
+    }
 }
@@ -62,6 +62,12 @@ use crate::model::DocumentInfo;
 ///
 /// - Returns `Ok(document)` if there were no fatal errors.
 /// - Returns `Err(errors)` if there were fatal errors.
+///
+/// The whole document is always laid out, even if only some of its pages are
+/// needed afterwards (e.g. for a thumbnail of the first page). Any page may
+/// depend on content further back through introspection, so stopping early
+/// could produce a page that differs from the one in the full document.
+/// Repeated compilations are fast due to memoization.
 #[typst_macros::time]
 pub fn compile<D>(world: &dyn World) -> Warned<SourceResult<D>>
 where
@@ -358,4 +364,5 @@ pub static ROUTINES: Routines = Routines {
     layout_image: typst_layout::layout_image,
     layout_equation_block: typst_layout::layout_equation_block,
     layout_equation_inline: typst_layout::layout_equation_inline,
+    layout_ruby: typst_layout::layout_ruby,
 };
@@ -290,6 +290,14 @@
 #test(("a", "b", "c").join(), "abc")
 #test("(" + ("a", "b", "c").join(", ") + ")", "(a, b, c)")
 
+--- array-join-last ---
+// Test the `last` separator.
+#test(().join(", ", last: " and "), none)
+#test(("a",).join(", ", last: " and "), "a")
+#test(("a", "b").join(", ", last: " and "), "a and b")
+#test(("a", "b", "c").join(", ", last: " and "), "a, b and c")
+#test(("a", "b", "c", "d").join(last: "|"), "abc|d")
+
 --- array-join-bad-values ---
 // Error: 2-22 cannot join boolean with boolean
 #(true, false).join()
@@ -38,3 +38,23 @@
 #assert(5 > 3)
 #assert.eq(15, 15)
 #assert.ne(10, 12)
+
+--- assert-in-function ---
+// Test that failing assertions in functions point to the assertion.
+#let columns(n) = {
+  // Error: 3-53 assertion failed: columns must be positive
+  assert(n > 0, message: "columns must be positive")
+  n
+}
+#test(columns(2), 2)
+#columns(-1)
+
+--- panic-in-function ---
+#let check(kind) = {
+  if kind not in ("a", "b") {
+    // Error: 5-32 panicked with: "unknown kind", "c"
+    panic("unknown kind", kind)
+  }
+}
+#check("a")
+#check("c")
@@ -31,6 +31,12 @@ Blue #move(dy: -0.15em)[🌊]
 // Error: 7-12 expected semicolon or line break
 #eval("1 2")
 
+--- eval-recursion ---
+// Nested evaluations count towards the call depth.
+// Error: 18-19 maximum function call depth exceeded
+#let f(s) = eval(s, scope: (f: f, s: s))
+#f("f(s)")
+
 --- eval-path-resolve ---
 // Test absolute path.
 #eval("image(\"/assets/images/tiger.jpg\", width: 50%)")
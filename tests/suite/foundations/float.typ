@@ -16,6 +16,16 @@
 // Error: 8-13 expected float, boolean, integer, decimal, ratio, or string, found type
 #float(float)
 
+--- float-constructor-parse ---
+#test(float(" 1e3 "), 1000.0)
+#test(float("+2.5"), 2.5)
+#test(float("-inf"), -float.inf)
+#test(float("1e308"), 1e308)
+
+--- float-constructor-parse-overflow ---
+// Error: 8-15 number too large
+#float("1e400")
+
 --- float-constructor-bad-value ---
 // Error: 8-15 invalid float: 1.2.3
 #float("1.2.3")
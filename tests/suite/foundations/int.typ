@@ -25,6 +25,41 @@
 #test(int(decimal("92492.193848921")), 92492)
 #test(int(decimal("-224.342211")), -224)
 
+--- int-constructor-parse ---
+// Test parsing integers from strings.
+#test(int(" 42\n"), 42)
+#test(int("+7"), 7)
+#test(int("-9223372036854775808"), -9223372036854775807 - 1)
+#test(int("1F", base: 16), 31)
+#test(int("0x1F", base: 16), 31)
+#test(int("0o17", base: 8), 15)
+#test(int("-0b101", base: 2), -5)
+#test(int("zz", base: 36), 1295)
+
+--- int-constructor-parse-overflow ---
+// Error: 6-27 number too large
+#int("9223372036854775808")
+
+--- int-constructor-parse-double-sign ---
+// Error: 6-11 invalid integer: -+5
+#int("-+5")
+
+--- int-constructor-parse-bad-digit ---
+// Error: 6-10 invalid integer: 12
+#int("12", base: 2)
+
+--- int-constructor-prefix-without-base ---
+// Error: 6-12 invalid integer: 0x1F
+#int("0x1F")
+
+--- int-constructor-bad-base ---
+// Error: 17-18 base must be between 2 and 36
+#int("1", base: 1)
+
+--- int-constructor-base-non-string ---
+// Error: 16-17 base is only supported for strings
+#int(10, base: 2)
+
 --- int-constructor-bad-type ---
 // Error: 6-10 expected integer, boolean, float, decimal, or string, found length
 #int(10pt)
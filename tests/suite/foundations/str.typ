@@ -53,8 +53,33 @@
 #test(str(-0987654321), "−987654321")
 #test(str(4 - 8), "−4")
 
+--- str-from-primitives ---
+// Test the `str` function with other primitives.
+#test(str(true), "true")
+#test(str(false), "false")
+#test(str(1.5), "1.5")
+#test(str(1.5pt), "1.5pt")
+#test(str(-2pt), "−2pt")
+#test(str(1cm), "28.35pt")
+#test(str(2pt + 1em), "2pt + 1em")
+#test(str(90deg), "90deg")
+#test(str(50%), "50%")
+#test(str(2fr), "2fr")
+#test(str(1pt) + "/" + str(12), "1pt/12")
+#test(str(2pt - 1em), "2pt + −1em")
+#test(str(-1.5deg), "−1.5deg")
+#test(str(float.inf * 1pt), "∞pt")
+#test(str(50% + 1pt), "50% + 1pt")
+#test(str(-50% - 1pt), "−50% + −1pt")
+#test(str(0% + 1pt), "1pt")
+#test(str(50% + 0pt), "50%")
+
+--- str-constructor-function ---
+// Error: 6-9 expected integer, float, decimal, boolean, length, angle, ratio, relative length, fraction, version, bytes, label, type, or string, found function
+#str(str)
+
 --- str-constructor-bad-type ---
-// Error: 6-8 expected integer, float, decimal, version, bytes, label, type, or string, found content
+// Error: 6-8 expected integer, float, decimal, boolean, length, angle, ratio, relative length, fraction, version, bytes, label, type, or string, found content
 #str([])
 
 --- str-constructor-bad-base ---
@@ -148,6 +173,30 @@
 --- string-codepoints ---
 #test("🏳️‍🌈!".codepoints(), ("🏳", "\u{fe0f}", "\u{200d}", "🌈", "!"))
 
+--- string-len-codepoints-clusters ---
+// Length counts bytes, which differs from codepoints and grapheme clusters.
+#let family = "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}"
+#test(family.len(), 18)
+#test(family.codepoints().len(), 5)
+#test(family.clusters().len(), 1)
+#test((family + "e\u{301}").clusters(), (family, "e\u{301}"))
+
+--- string-normalize ---
+#let decomposed = "e\u{301}"
+#let composed = "\u{e9}"
+#test(decomposed == composed, false)
+#test(decomposed.normalize(), composed)
+#test(decomposed.normalize(form: "nfc"), composed)
+#test(composed.normalize(form: "nfd"), decomposed)
+#test(composed.normalize().normalize(), composed)
+#test(decomposed.normalize(form: "nfd"), decomposed)
+#test("\u{fb01}".normalize(form: "nfkc"), "fi")
+#test("\u{fb01}".normalize(form: "nfc"), "\u{fb01}")
+
+--- string-normalize-bad-form ---
+// Error: 22-26 expected "nfc", "nfd", "nfkc", or "nfkd"
+#"a".normalize(form: "nf")
+
 --- string-contains ---
 // Test the `contains` method.
 #test("abc".contains("b"), true)
@@ -21,3 +21,25 @@
 #test(repr(auto), "auto")
 #test(repr(type(none)), "type(none)")
 #test(repr(type(auto)), "type(auto)")
+
+--- type-names ---
+#test(str(type(1)), "integer")
+#test(str(type(1.5)), "float")
+#test(str(type("a")), "string")
+#test(str(type(true)), "boolean")
+#test(str(type(1pt)), "length")
+#test(str(type(())), "array")
+#test(str(type((:))), "dictionary")
+#test(str(type([])), "content")
+#test(str(type(x => x)), "function")
+#test(str(type(none)), "none")
+#test(str(type(auto)), "auto")
+#test(str(type(type)), "type")
+
+--- type-in-array ---
+#test(type(1) in (int, float), true)
+#test(type("1") in (int, float), false)
+
+--- type-string-comparison ---
+// Types are not equal to their names.
+#test(type(1) == "integer", false)
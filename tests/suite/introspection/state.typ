@@ -61,3 +61,13 @@ Was: #context {
 // Hint: 2-26 try wrapping this in a `context` expression
 // Hint: 2-26 the `context` expression should wrap everything that depends on this function
 #state("key").at(<label>)
+
+--- state-identical-blocks ---
+// Identical content that depends on the context must not be served from the
+// layout cache with a stale result.
+#let s = state("identical", 0)
+#for _ in range(3) {
+  s.update(n => n + 1)
+  block(context [#metadata(s.get()) <identical>])
+}
+#context test(query(<identical>).map(m => m.value), (1, 2, 3))
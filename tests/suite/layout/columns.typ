@@ -129,3 +129,55 @@ Hallo
 A
 #colbreak(weak: true)
 B
+
+--- colbreak-weak-at-column-start ---
+// A weak column break at the start of a column is a no-op, so both markers
+// end up in the first and second column of the first page.
+#columns(2)[
+  #colbreak(weak: true)
+  #metadata(none) <first>
+  #colbreak()
+  #metadata(none) <second>
+]
+
+#context {
+  let first = locate(<first>).position()
+  let second = locate(<second>).position()
+  test(first.page, 1)
+  test(second.page, 1)
+  test(first.x < second.x, true)
+}
+
+--- columns-separator-empty ---
+// No rules are drawn next to empty columns.
+#columns(3, separator: 1pt + red)[]
+
+--- columns-separator-tag-only ---
+// No rule is drawn next to a column that only holds invisible content.
+#set page(width: 120pt, height: auto)
+#columns(2, separator: 1pt + red)[
+  #rect(width: 100%, height: 20pt, fill: aqua)
+  #colbreak()
+  #metadata(none) <meta>
+]
+
+--- columns-separator ---
+// The rule in each gutter spans the height of the tallest column.
+#set page(width: 120pt, height: auto)
+#columns(3, gutter: 12pt, separator: 1pt + red)[
+  #rect(width: 100%, height: 30pt, fill: aqua)
+  #colbreak()
+  #rect(width: 100%, height: 10pt, fill: aqua)
+  #colbreak()
+  #rect(width: 100%, height: 20pt, fill: aqua)
+]
+
+--- columns-separator-rtl ---
+// Rules are mirrored along with the columns in right-to-left text.
+#set page(width: 120pt, height: auto)
+#set text(dir: rtl)
+#columns(3, gutter: 12pt, separator: 1pt + red)[
+  #rect(width: 100%, height: 30pt, fill: aqua)
+  #colbreak()
+  #rect(width: 50%, height: 10pt, fill: aqua)
+]
@@ -125,6 +125,22 @@ Paragraph
   #text(size: 8pt, block(above: 1.25em)[B])
 ]
 
+--- block-spacing-collapse-distance ---
+// Adjacent spacings collapse to the larger one and explicit spacing between
+// the blocks is added on top.
+#block(height: 0pt, {
+  block(below: 10pt)[#metadata(none) <a>]
+  block(above: 20pt, below: 20pt)[#metadata(none) <b>]
+  v(5pt)
+  block(above: 10pt)[#metadata(none) <c>]
+})
+
+#context {
+  let y(label) = locate(label).position().y
+  test(y(<b>) - y(<a>), 20pt)
+  test(y(<c>) - y(<b>), 25pt)
+}
+
 --- block-fixed-height ---
 #set page(height: 100pt)
 #set align(center)
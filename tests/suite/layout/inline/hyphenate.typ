@@ -112,6 +112,35 @@ Tras el estallido de la contienda Ruiz-Giménez fue detenido junto a sus
 dos hermanos y puesto bajo custodia por las autoridades republicanas, con
 el objetivo de protegerle de las patrullas de milicianos.
 
+--- hyphenate-exceptions ---
+#let lines(..args) = measure(block(
+  width: 30pt,
+  text(hyphenate: true, ..args, "database"),
+)).height
+#context {
+  let one = lines(hyphenate: false)
+  test(lines() > one, true)
+  test(lines(hyphenation-exceptions: ("database",)), one)
+  test(lines(hyphenation-exceptions: ("DataBase",)), one)
+  test(lines(hyphenation-exceptions: ("data-base",)) > one, true)
+}
+
+--- hyphenate-exceptions-mixed ---
+// Exceptions that only apply to part of a paragraph.
+#let lines(body) = measure(block(
+  width: 30pt,
+  text(hyphenate: true, body),
+)).height
+#context {
+  let one = lines(text(hyphenate: false)[database.])
+  test(lines[database.] > one, true)
+  test(lines[#text(hyphenation-exceptions: ("database",))[database].], one)
+}
+
+--- hyphenate-exceptions-invalid ---
+// Error: 35-46 expected array, found string
+#set text(hyphenation-exceptions: "data-base")
+
 --- costs-widow-orphan ---
 #set page(height: 60pt)
 
@@ -74,6 +74,32 @@
 #set page(fill: none)
 #rect(fill: green)
 
+--- page-flush-bottom ---
+// The gaps between paragraphs on the first page are stretched to reach the
+// bottom, while the spacing around the heading stays the same. The last page
+// keeps a ragged bottom.
+#set page(height: 160pt, flush-bottom: 20pt)
+#set par(spacing: 6pt)
+#let part = [
+  = Heading
+  #metadata(none) <a> #box(width: 100%, height: 30pt, fill: aqua)
+
+  #metadata(none) <b> #box(width: 100%, height: 30pt, fill: aqua)
+
+  #box(width: 100%, height: 30pt, fill: aqua)
+]
+
+#part
+#part
+
+#context {
+  let y(target) = query(target).map(it => it.location().position().y)
+  let (a, b, h) = (y(<a>), y(<b>), y(heading))
+  test(query(<a>).map(it => it.location().page()), (1, 2))
+  test(a.at(0) - h.at(0), a.at(1) - h.at(1))
+  test(b.at(0) - a.at(0) > b.at(1) - a.at(1), true)
+}
+
 --- page-margin-uniform ---
 // Set all margins at once.
 #[
@@ -6,6 +6,15 @@
 #test((100% + 2pt - 2pt).length, 0pt)
 #test((56% + 2pt - 56%).ratio, 0%)
 
+--- relative-arithmetic ---
+// Test arithmetic on relative lengths.
+#test(-(20% + 5pt), -20% - 5pt)
+#test((50% + 1cm) - 2mm, 50% + 8mm)
+#test((50% + 1cm) - 10%, 40% + 1cm)
+#test((50% + 1cm) - (50% + 1cm), 0% + 0pt)
+#test(2 * (10% + 3pt), 20% + 6pt)
+#test((10% + 3pt) / 2, 5% + 1.5pt)
+
 --- double-percent ---
 // Test for two percent signs in a row.
 #3.1%%
@@ -28,6 +28,26 @@ A #h(1fr) B
 // Error: 10-13 missing argument: amount
 Totally #h() ignored
 
+--- spacing-v-weak-page-start ---
+// Weak spacing at the start of a page vanishes.
+#v(20pt, weak: true)
+#block(height: 0pt)[#metadata(none) <top>]
+#context test(locate(<top>).position().y, 10pt)
+
+--- spacing-v-weak-collapse ---
+// Adjacent weak spacings collapse to the largest one, which also replaces the
+// spacing around the blocks.
+#let stack(..spacings) = measure(block({
+  block(height: 0pt)
+  for s in spacings.pos() { v(s, weak: true) }
+  block(height: 0pt)
+})).height
+#context {
+  test(stack(5pt, 8pt), 8pt)
+  test(stack(8pt, 5pt), 8pt)
+  test(stack(2pt), 2pt)
+}
+
 --- issue-3624-spacing-behaviour ---
 // Test that metadata after spacing does not force a new paragraph.
 #{
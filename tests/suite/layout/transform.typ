@@ -73,6 +73,22 @@ Hello #rotated[World]!\
 #set rotate(reflow: true)
 Hello #rotated[World]!
 
+--- transform-rotate-reflow-inline-height ---
+// An inline reflowed rotation reports its rotated size to the line.
+#context {
+  let body = [Rotated label]
+  let plain = measure(body)
+  let rotated = measure(box(rotate(90deg, reflow: true, body)))
+  test(calc.abs((rotated.height - plain.width) / 1pt) < 0.01, true)
+  test(calc.abs((rotated.width - plain.height) / 1pt) < 0.01, true)
+
+  let line = measure(block(width: 200pt)[A #box(rotate(90deg, reflow: true, body)) B])
+  test(line.height >= rotated.height, true)
+
+  let unreflowed = measure(box(rotate(90deg, body)))
+  test(unreflowed, plain)
+}
+
 --- transform-scale ---
 // Test that scaling impacts layout.
 #set page(width: 200pt)
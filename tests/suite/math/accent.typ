@@ -42,3 +42,10 @@ $tilde(U, size: #1.1em), x^tilde(U, size: #1.1em), sscript(tilde(U, size: #1.1em
   macron(bb(#c)), dot(cal(#c)), diaer(upright(#c)), breve(bold(#c)),
   circle(bold(upright(#c))), caron(upright(sans(#c))), arrow(bold(frak(#c)))$
 $test(i) \ test(j)$
+
+--- math-accent-keeps-base-width ---
+#context {
+  let base = measure($x$)
+  test(measure($accent(x, \u{0301})$).width, base.width)
+  test(measure($arrow(x)$).width, base.width)
+}
@@ -34,6 +34,17 @@ $ lr(a/b\]) = a = lr(\{a/b) $
 $ lr(]sum_(x=1)^n x], size: #70%)
   < lr((1, 2), size: #200%) $
 
+--- math-lr-stretch-geometry ---
+// Delimiters are stretched to exactly the extent of their content around the
+// math axis, or to an explicitly given size.
+#let pt(length) = calc.round(length.pt(), digits: 3)
+#let column = math.mat(delim: none, ..range(12).map(i => ([#i],)))
+#context {
+  let h(eq) = measure(eq).height
+  test(pt(h($ lr((#column)) $)), pt(h($ #column $)))
+  test(pt(h($ lr((x), size: #100pt) $)), 100.0)
+}
+
 --- math-lr-shorthands ---
 // Test predefined delimiter pairings.
 $floor(x/2), ceil(x/2), abs(x), norm(x)$
@@ -45,3 +45,11 @@ $ a_1/b_2, 1/f(x), zeta(x)/2, "foo"[|x|]/2 \
 --- math-frac-gap ---
 // Test that the gap above and below the fraction rule is correct.
 $ sqrt(n^(2/3)) $
+
+--- math-frac-width ---
+// A fraction is as wide as the wider of numerator and denominator.
+#context {
+  let w(eq) = measure(eq).width
+  test(w($frac(a, b c d)$), w($frac(b c d, a)$))
+  test(w($frac(b c d, b c d)$), w($frac(a, b c d)$))
+}
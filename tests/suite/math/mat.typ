@@ -34,6 +34,18 @@ $ mat(
 $ mat(1, 2; 3, 4) $
 $ a + mat(delim: #none, 1, 2; 3, 4) + b $
 
+--- math-mat-delim-size ---
+// Delimiters of a tall matrix are assembled to exceed the matrix by 10% of
+// its height, and they are centered on it.
+#let pt(length) = calc.round(length.pt(), digits: 3)
+#let column(delim) = math.mat(delim: delim, ..range(12).map(i => ([#i],)))
+#context {
+  let h(body) = measure(math.equation(block: true, body)).height
+  let bare = h(column(none))
+  test(pt(h(column("("))), pt(1.1 * bare))
+  test(pt(h(column("["))), pt(1.1 * bare))
+}
+
 --- math-mat-delim-direct ---
 // Test alternative math delimiter directly in call.
 #set align(center)
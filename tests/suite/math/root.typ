@@ -32,6 +32,22 @@ $ v = sqrt((1/2) / (4/5))
    = root(4, ((1/2) / (3/4)) / ((1/2) / (3/4))) $
 $ v = sqrt(a +\ b) $
 
+--- math-root-tall-radicand-geometry ---
+// A tall radical sign is assembled to exactly the radicand's height plus the
+// gap and rule above it, so it grows by as much as its radicand. Its width
+// doesn't depend on the radicand's width.
+#let pt(length) = calc.round(length.pt(), digits: 3)
+#let column(n) = math.mat(delim: none, ..range(n).map(i => ([#i],)))
+#context {
+  let h(body) = measure(math.equation(block: true, body)).height
+  test(
+    pt(h(math.sqrt(column(14))) - h(math.sqrt(column(12)))),
+    pt(h(column(14)) - h(column(12))),
+  )
+  let w(eq) = measure(eq).width
+  test(pt(w($sqrt(1/2)$) - w($1/2$)), pt(w($sqrt(1/2 x)$) - w($1/2 x$)))
+}
+
 --- math-root-large-index ---
 // Test large index.
 $ root(2, x) quad
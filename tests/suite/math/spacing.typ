@@ -15,6 +15,17 @@ $f(x), zeta(x), "frac"(x)$ \
 $a+dots.c+b$
 $f(x) sin(y)$
 
+--- math-spacing-classes ---
+// Binary operators get medium space (4/18 em) and relations thick space
+// (5/18 em) on both sides.
+#let pt(length) = calc.round(length.to-absolute().pt(), digits: 3)
+#context {
+  let w(eq) = measure(eq).width
+  let bare = w($x$) + w($y$)
+  test(pt(w($x + y$) - w($+$) - bare), pt(2 * 4em / 18))
+  test(pt(w($x = y$) - w($=$) - bare), pt(2 * 5em / 18))
+}
+
 --- math-spacing-kept-spaces ---
 // Test ignored vs non-ignored spaces.
 $f (x), f(x)$ \
@@ -116,6 +116,37 @@ Not in heading
 // Hint: 1:19-1:25 you can enable heading numbering with `#set heading(numbering: "1.")`
 Cannot be used as @intro
 
+--- heading-keep-with-next ---
+// The heading and two lines fit on the first page, but three don't, so the
+// heading moves to the second page with the paragraph.
+#set page(width: 100pt, height: 60pt, margin: 0pt)
+#set text(size: 10pt, top-edge: 10pt, bottom-edge: 0pt)
+#set par(leading: 5pt, spacing: 5pt)
+#show heading: set text(size: 10pt)
+#show heading: set block(above: 5pt, below: 5pt)
+#set heading(keep-with-next: 3)
+
+#block(width: 100%, height: 15pt, fill: aqua)
+= Heading <h>
+A \ B \ C \ D
+
+#context test(locate(<h>).page(), 2)
+
+--- heading-keep-with-next-fits ---
+// With two lines to keep, the heading stays on the first page.
+#set page(width: 100pt, height: 60pt, margin: 0pt)
+#set text(size: 10pt, top-edge: 10pt, bottom-edge: 0pt)
+#set par(leading: 5pt, spacing: 5pt)
+#show heading: set text(size: 10pt)
+#show heading: set block(above: 5pt, below: 5pt)
+#set heading(keep-with-next: 2)
+
+#block(width: 100%, height: 15pt, fill: aqua)
+= Heading <h>
+A \ B \ C \ D
+
+#context test(locate(<h>).page(), 1)
+
 --- heading-html-basic html ---
 // level 1 => h2
 // ...
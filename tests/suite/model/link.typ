@@ -75,3 +75,15 @@ Text <hey>
 Text <hey>
 // Error: 2-20 label `<hey>` occurs multiple times in the document
 #link(<hey>)[Nope.]
+
+--- link-html-label html ---
+= Introduction <intro>
+See #link(<intro>)[the introduction].
+
+= Spaced
+#label("a b")
+
+= Dashed <a-b>
+Back to #link(<a-b>)[dashed], #link(label("a b"))[spaced], and #link(<word>)[a word].
+
+Just #[words] <word> here.
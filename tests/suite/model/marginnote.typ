@@ -0,0 +1,66 @@
+// Test margin notes.
+
+--- marginnote-basic ---
+#set page(width: 160pt, height: auto, margin: (x: 50pt, y: 10pt))
+#set text(size: 8pt)
+The mitochondria is the powerhouse of the cell.
+#marginnote(text(fill: blue)[Citation needed.])
+It has its own DNA.
+#marginnote(side: left, text(fill: red)[Left note.])
+
+--- marginnote-sides ---
+#{
+  marginnote(clearance: 0pt)[#metadata(none) <outside>]
+  marginnote(side: left, clearance: 1pt)[#metadata(none) <left>]
+}
+#context {
+  test(locate(<outside>).position(), (page: 1, x: 110pt, y: 10pt))
+  test(locate(<left>).position(), (page: 1, x: 1pt, y: 10pt))
+}
+
+--- marginnote-inside ---
+#marginnote(side: "inside", clearance: 0pt)[#metadata(none) <inside>]
+#context test(locate(<inside>).position().x, 0pt)
+
+--- marginnote-collision ---
+#{
+  marginnote(clearance: 0pt)[#metadata(none) <first>]
+  marginnote(clearance: 0pt, gap: 5pt)[#metadata(none) <second>]
+}
+#context {
+  test(locate(<first>).position().y, 10pt)
+  test(locate(<second>).position().y, 15pt)
+}
+
+--- marginnote-styles ---
+// Set rules and the text size at the note apply to it.
+#{
+  set marginnote(side: left, clearance: 0pt)
+  marginnote[#metadata(none) <set>]
+}
+#{
+  set text(size: 20pt)
+  marginnote(clearance: 0.5em)[#metadata(none) <em>]
+}
+#context {
+  test(locate(<set>).position().x, 0pt)
+  test(locate(<em>).position().x, 120pt)
+}
+
+--- marginnote-overflow ---
+#set page(height: 40pt)
+// Warning: 2-33 margin notes do not fit into the margin
+// Hint: 2-33 try shortening the notes or moving some of them to another page
+#marginnote(block(height: 50pt))
+
+--- marginnote-side-invalid ---
+// Error: 19-27 expected "outside", "inside", or alignment
+#marginnote(side: "center")[]
+
+--- marginnote-side-bad-alignment ---
+// Error: 19-25 must be `left` or `right`
+#marginnote(side: center)[]
+
+--- marginnote-missing-body ---
+// Error: 2-14 missing argument: body
+#marginnote()
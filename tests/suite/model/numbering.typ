@@ -16,6 +16,14 @@
 // Arabic.
 #t(pat: "1", "0", "1", "2", "3", "4", "5", "6", 107, "107", "108")
 
+// Latin.
+#t(pat: "a", "-", "a", "b", "c", 25, "y", "z", "aa", "ab", 702, "zz", "aaa")
+#t(pat: "A", "-", "A", "B", 26, "Z", "AA")
+
+// Roman.
+#t(pat: "i", "n", "i", "ii", "iii", "iv", "v", 9, "ix", 1994, "mcmxciv")
+#t(pat: "I", "N", "I", "II", 3999, "MMMCMXCIX", "I̅V̅")
+
 // Greek.
 #t(
   pat: "α",
@@ -105,6 +113,12 @@
 #t(pat: "⓵", 1, "⓵")
 #t(pat: "⓵", 10, "⓾")
 
+--- numbering-multiple ---
+#test(numbering("1.1", 1, 2), "1.2")
+#test(numbering("1.a.i", 2, 3, 4), "2.c.iv")
+#test(numbering("A.1", 0, 0), "-.0")
+#test(numbering("(1)", 9223372036854775807), "(9223372036854775807)")
+
 --- numbering-negative ---
 // Error: 17-19 number must be at least zero
 #numbering("1", -1)
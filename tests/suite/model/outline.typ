@@ -150,6 +150,11 @@ A
 #outline(target: metadata)
 #metadata("hello")
 
+--- outline-table-target ---
+// Error: 18-23 table is not locatable
+// Hint: 18-23 try to select the figures containing them instead: `figure.where(kind: table)`
+#outline(target: table)
+
 --- issue-2530-outline-entry-panic-text ---
 // Outline entry (pre-emptive)
 // Error: 2-48 cannot outline text
@@ -26,10 +26,39 @@ But, soft! what light through yonder window breaks?
 
 It is the east, and Juliet is the sun.
 
+--- par-leading-measured ---
+// Leading is the gap between the bottom edge of one line and the top edge of
+// the next, so a larger line only grows its own extent.
+#set text(size: 10pt, top-edge: 1em, bottom-edge: "baseline")
+#set par(leading: 5pt)
+#let height(body) = measure(block(width: 100pt, body)).height
+#context {
+  test(height[A], 10pt)
+  test(height[A \ A], 25pt)
+  test(height[A \ A \ A], 40pt)
+  test(height[A #text(20pt)[A] \ A], 35pt)
+  test(height[A \ A #text(20pt)[A] \ A], 55pt)
+}
+
 --- par-spacing-context ---
 #set par(spacing: 10pt)
 #context test(par.spacing, 10pt)
 
+--- par-spacing-after-heading ---
+// Test that the heading's `below` spacing replaces the paragraph spacing
+// before the first paragraph, while the paragraph spacing applies between
+// the paragraphs.
+#set text(size: 10pt, top-edge: 10pt, bottom-edge: 0pt)
+#set par(spacing: 20pt, leading: 2pt)
+#show heading: set text(size: 10pt)
+#show heading: set block(above: 3pt, below: 7pt)
+#context test(measure[
+  = Heading
+  First paragraph.
+
+  Second paragraph.
+].height, 10pt + 7pt + 10pt + 20pt + 10pt)
+
 --- par-first-line-indent ---
 #set par(first-line-indent: 12pt, spacing: 5pt, leading: 5pt)
 #show heading: set text(size: 10pt)
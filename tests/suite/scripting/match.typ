@@ -0,0 +1,197 @@
+// Test match expressions.
+
+--- match-values ---
+#let describe(x) = match x {
+  none: "nothing",
+  auto: "automatic",
+  1: "one",
+  "hi": "greeting",
+  (1, 2): "pair",
+  _: "other",
+}
+
+#test(describe(none), "nothing")
+#test(describe(auto), "automatic")
+#test(describe(1), "one")
+#test(describe(1.0), "one")
+#test(describe("hi"), "greeting")
+#test(describe((1, 2)), "pair")
+#test(describe(2), "other")
+#test(describe([hi]), "other")
+
+--- match-first-arm-wins ---
+#test(match 1 { 1: "a", 1: "b", _: "c" }, "a")
+#test(match 1 { _: "a", 1: "b" }, "a")
+
+--- match-expressions-as-patterns ---
+#let limit = 3
+#test(match 4 - 1 { limit: "limit", _: "other" }, "limit")
+#test(match 6 { limit * 2: "double", _: "other" }, "double")
+
+--- match-guard ---
+#let sign(n) = match n {
+  0: "zero",
+  let n if n < 0: "negative",
+  _ if n > 100: "large",
+  _: "positive",
+}
+
+#test(sign(0), "zero")
+#test(sign(-3), "negative")
+#test(sign(1000), "large")
+#test(sign(7), "positive")
+
+--- match-guard-ranges ---
+#let grade(score) = match score {
+  _ if score >= 90: "A",
+  _ if score >= 80: "B",
+  _: "C",
+}
+
+#test(grade(95), "A")
+#test(grade(85), "B")
+#test(grade(12), "C")
+
+--- match-type ---
+#let kind(x) = match type(x) {
+  int: "int",
+  str: "str",
+  array: "array",
+  _: "other",
+}
+
+#test(kind(1), "int")
+#test(kind("a"), "str")
+#test(kind(()), "array")
+#test(kind(1pt), "other")
+
+--- match-destructuring-array ---
+#let shape(x) = match x {
+  let (): "empty",
+  let (a,): "one: " + str(a),
+  let (a, b): "two: " + str(a + b),
+  let (first, ..rest): "many: " + str(first) + " and " + str(rest.len()),
+  _: "not an array",
+}
+
+#test(shape(()), "empty")
+#test(shape((1,)), "one: 1")
+#test(shape((1, 2)), "two: 3")
+#test(shape((1, 2, 3, 4)), "many: 1 and 3")
+#test(shape(none), "not an array")
+
+--- match-destructuring-nested ---
+#let f(x) = match x {
+  let ((a, b), c): a + b + c,
+  let (a, (b, c)): a * b * c,
+  _: none,
+}
+
+#test(f(((1, 2), 3)), 6)
+#test(f((2, (3, 4))), 24)
+#test(f((1, 2)), none)
+#test(f(((1, 2, 3), 4)), none)
+
+--- match-destructuring-dict ---
+#let greet(person) = match person {
+  let (name: n, title: t): t + " " + n,
+  let (name,): "Hello " + name,
+  let (..rest): str(rest.len()) + " fields",
+}
+
+#test(greet((name: "Ada", title: "Countess")), "Countess Ada")
+#test(greet((name: "Bob", age: 3)), "Hello Bob")
+#test(greet((age: 3)), "1 fields")
+
+--- match-destructuring-placeholder ---
+#test(match (1, 2, 3) { let (_, x, _): x, _: none }, 2)
+
+--- match-binding-scope ---
+#let x = "outer"
+#test(match 1 { let x: x }, 1)
+#test(x, "outer")
+
+--- match-binding-scope-failed-guard ---
+#let x = "outer"
+#test(match 1 { let x if x > 5: "a", _: x }, "outer")
+
+--- match-closure-capture ---
+#let f(default) = v => match v {
+  let (a, b): a + b,
+  _: default,
+}
+
+#test(f(0)((1, 2)), 3)
+#test(f(0)(none), 0)
+
+--- match-body-block ---
+#let out = match 2 {
+  1: [one],
+  2: {
+    let y = 3
+    y * 2
+  },
+  _: none,
+}
+#test(out, 6)
+
+--- match-in-code-block ---
+#{
+  let v = "b"
+  test(match v { "a": 1, "b": 2, _: 3 }, 2)
+}
+
+--- match-return ---
+#let f(x) = {
+  match x {
+    0: return "early",
+    _: none,
+  }
+  "late"
+}
+
+#test(f(0), "early")
+#test(f(1), "late")
+
+--- match-as-identifier ---
+// `match` is only a keyword where it starts a match expression.
+#let match = 1
+#test(match, 1)
+#test(match + 1, 2)
+#let match(x) = x * 2
+#test(match(2), 4)
+#test("abc".match("b").text, "b")
+#test((match: 5).match, 5)
+#for match in (1,) { test(match, 1) }
+
+--- match-in-markup ---
+// A match expression can directly follow a hash in markup.
+#let x = 2
+#let out = [#match x { 1: "one", 2: "two", _: "many" }]
+#test(out, [two])
+
+--- match-in-markup-missing-arms ---
+// Error: 9 expected match arms
+#match 3
+
+--- match-no-arm ---
+// Error: 16-17 no match arm matched 3
+// Hint: 16-17 add a `_` arm to handle all other values
+#let x = match 3 { 1: "a", 2: "b" }
+
+--- match-no-arm-guard ---
+// Error: 16-17 no match arm matched 3
+// Hint: 16-17 add a `_` arm to handle all other values
+#let x = match 3 { let n if n < 0: n }
+
+--- match-guard-not-bool ---
+// Error: 25-26 expected boolean, found integer
+#let x = match 3 { _ if 1: "a" }
+
+--- match-missing-colon ---
+// Error: 21 expected colon
+#let x = match 3 { 1 }
+
+--- match-missing-arms ---
+// Error: 17 expected match arms
+#let x = match 3
@@ -204,6 +204,9 @@
 #test((1, 2, 3) == (1, 2.0) + (3,), true)
 #test((:) == (a: 1), false)
 #test((a: 2 - 1.0, b: 2) == (b: 2, a: 1), true)
+#test((x: (a: 1, b: 2)) == (x: (b: 2, a: 1)), true)
+#test(((a: 1, b: 2),) == ((b: 2, a: 1),), true)
+#test((1, 2) == (2, 1), false)
 #test("a" != "a", false)
 #test(decimal("1.234") == decimal("1.23400000000"), true)
 #test(235 == decimal("235.0"), true)
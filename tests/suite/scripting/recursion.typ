@@ -59,3 +59,9 @@ $ x $
 // Hint: 22-33 check whether the show rule matches its own output
 #show heading: it => heading[it]
 $ #heading[hi] $
+
+--- recursion-show-same-element ---
+// Error: 28-44 maximum show rule depth exceeded
+// Hint: 28-44 check whether the show rule matches its own output
+#show heading: it => block(heading(it.body))
+= Hi
@@ -9,3 +9,20 @@
 --- upper-bad-type ---
 // Error: 8-9 expected string or content, found integer
 #upper(1)
+
+--- upper-sharp-s ---
+#test(upper("Straße"), "STRASSE")
+#test(lower("STRASSE"), "strasse")
+
+--- title-case ---
+#test(title("the lord of the rings"), "The Lord Of The Rings")
+#test(title(skip: ("of", "the"), "the lord of the rings"), "The Lord of the Rings")
+#test(title("it's well-known"), "It's Well-Known")
+
+--- title-case-content-mid-paragraph ---
+// The first word of the title is capitalized even if it is a skip word and
+// the title starts in the middle of a paragraph.
+#context test(
+  measure[Read #title(skip: ("of", "the"))[the lord of the rings] now.].width,
+  measure[Read The Lord of the Rings now.].width,
+)
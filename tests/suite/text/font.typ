@@ -154,3 +154,22 @@ The number 123.
 #set text(-1pt)
 
 a
+
+--- text-missing-glyphs-error ---
+#set text(fallback: false, missing: "error")
+// Error: 1-5 no font has glyphs for the text "عربي"
+// Hint: 1-5 check the font list or enable font fallback
+عربي
+
+--- text-missing-glyphs-warn ---
+#set text(fallback: false, missing: "warn")
+// Warning: 1-5 no font has glyphs for the text "عربي"
+// Hint: 1-5 check the font list or enable font fallback
+عربي
+
+--- text-missing-glyphs-with-fallback ---
+// With fallback enabled, the hint does not suggest to enable it.
+#set text(missing: "error")
+// Error: 1-2 no font has glyphs for the text "𓀀"
+// Hint: 1-2 add a font that covers this text to the font list
+𓀀
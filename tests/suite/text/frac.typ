@@ -0,0 +1,36 @@
+--- textfrac-fields ---
+#let f = textfrac[1][2]
+#test(f.func(), textfrac)
+#test(f.num, [1])
+#test(f.denom, [2])
+
+--- textfrac-synthesized-is-smaller ---
+#context {
+  let synth = measure(textfrac(typographic: false)[1][2])
+  let inline = measure[1/2]
+  test(synth.width < inline.width, true)
+}
+
+--- textfrac-precomposed ---
+// Libertinus Serif has a glyph for `½`, which is used directly.
+#context test(measure(textfrac[1][2]).width, measure[½].width)
+
+--- textfrac-without-precomposed-glyph ---
+// Simulate a font without a glyph for `½` by excluding it from the coverage.
+// The fraction is then shaped with the font's `frac` feature instead.
+#set text(font: ((name: "Libertinus Serif", covers: regex("[^½]")),))
+#context test(
+  measure(textfrac[1][2]).width,
+  measure(text(fractions: true)[1⁄2]).width,
+)
+
+--- textfrac-frac-feature ---
+// There is no precomposed character for 5/16, so the `frac` feature is used.
+#context test(
+  measure(textfrac[5][16]).width,
+  measure(text(fractions: true)[5⁄16]).width,
+)
+
+--- textfrac-missing-denom ---
+// Error: 2-13 missing argument: denom
+#textfrac[1]
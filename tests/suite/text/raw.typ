@@ -68,6 +68,14 @@ The keyword ```rust let```.
         B
     ```
 
+--- raw-tab-size-crlf ---
+// Tab stops are counted from the start of each line, whatever the line ending.
+#set raw(tab-size: 4)
+#context test(
+  measure(raw("a\r\n\tb")).width,
+  measure(raw("a\n\tb")).width,
+)
+
 --- raw-tab-size ---
 #set raw(tab-size: 8)
 
@@ -78,6 +86,25 @@ Year	Month	Day
 2002	3	10
 ```
 
+--- raw-whitespace-preserved ---
+// Tabs expand to the next tab stop, while trailing spaces and blank lines are
+// kept as they are.
+#set raw(tab-size: 4)
+#let expected = ("a  ", "", "", "    b", "    c", "   d")
+#context {
+  let _ = measure({
+    show raw.line: it => {
+      test(it.count, 6)
+      test(it.text, expected.at(it.number - 1))
+    }
+    raw("a  \n\n\n\tb\n  \tc\n   d", block: true)
+  })
+}
+
+--- raw-inline-spaces ---
+// Consecutive spaces in inline raw text are not collapsed.
+#context test(measure(`a  b`).width > measure(`a b`).width, true)
+
 --- raw-syntaxes ---
 #set page(width: 180pt)
 #set text(6pt)
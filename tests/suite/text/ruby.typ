@@ -0,0 +1,37 @@
+--- ruby-mono-mismatch ---
+// Error: 2-27 expected 2 annotation parts, found 1
+// Hint: 2-27 separate the annotations of the characters with spaces
+#ruby(mode: "mono")[ab][x]
+
+--- ruby-mono-styled ---
+// Warning: 28-57 styling within a mono ruby is ignored
+// Hint: 28-57 apply the styling to the whole ruby instead
+#context { let _ = measure(ruby(mode: "mono")[*a*b][x y]) }
+
+--- ruby-group ---
+// The whole annotation is centered over the whole base text.
+#set text(lang: "ja", font: "Noto Serif CJK JP")
+#ruby[東京][とうきょう]は日本の首都です。
+
+#ruby[字][ながいよみかた]
+
+--- ruby-mono ---
+// Each character is annotated separately.
+#set text(lang: "ja", font: "Noto Serif CJK JP")
+#ruby(mode: "mono")[漢字][かん じ]を学ぶ。
+
+--- ruby-mono-whitespace ---
+// Whitespace in the base text does not need an annotation part.
+#set text(lang: "ja", font: "Noto Serif CJK JP")
+#context test(
+  measure(ruby(mode: "mono")[漢 字][かん じ]).width,
+  measure(ruby(mode: "mono")[漢字][かん じ]).width,
+)
+
+--- ruby-group-width ---
+// A group is as wide as the wider of base and annotation.
+#set text(lang: "ja", font: "Noto Serif CJK JP")
+#context test(
+  measure(ruby[字][ながいよみかた]).width,
+  measure(text(0.5em)[ながいよみかた]).width,
+)
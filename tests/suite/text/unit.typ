@@ -0,0 +1,16 @@
+// Test physical quantities.
+
+--- unit-fields ---
+#test(unit[10][km].value, [10])
+#test(unit("10", "km").unit, [km])
+
+--- unit-no-break ---
+// Neither the value nor a compound unit are broken across lines.
+#context {
+  let height = measure(unit[120][km/h]).height
+  test(measure(block(width: 1pt, unit[120][km/h])).height, height)
+}
+
+--- unit-missing-unit ---
+// Error: 2-10 missing argument: unit
+#unit[10]
@@ -79,3 +79,12 @@ Expanded by height.
 // Test that sizing a circle beyond the page width correctly overflows the page.
 #set page(height: 100pt)
 #circle(width: 150%)
+
+--- circle-clip-fills-box ---
+// A clipping circle lays out its content in the full bounding box.
+#context {
+  let body = block(width: 100%, height: 100%)
+  test(measure(circle(radius: 10pt, inset: 0pt, clip: true, body)).width, 20pt)
+  let inner = layout(size => test(size, (width: 20pt, height: 20pt)))
+  let _ = measure(circle(radius: 10pt, inset: 0pt, clip: true, inner))
+}
@@ -161,3 +161,19 @@
     curve.line((10pt, 10pt)),
   ),
 )
+
+--- curve-svg ---
+#test(
+  curve.svg("M0 0 L10 5 Q 10 10 20 10 Z"),
+  (
+    curve.move((0pt, 0pt)),
+    curve.line((10pt, 5pt)),
+    curve.quad((10pt, 10pt), (20pt, 10pt)),
+    curve.close(mode: "straight"),
+  ),
+)
+#test(curve.svg(""), ())
+
+--- curve-svg-unknown-command ---
+// Error: 12-23 failed to parse SVG path data (unknown command "X")
+#curve.svg("M 0 0 X 5")
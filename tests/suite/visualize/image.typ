@@ -75,6 +75,22 @@ A #box(image("/assets/images/tiger.jpg", height: 1cm, width: 80%)) B
 // Error: 8-29 file not found (searched at tests/suite/visualize/path/does/not/exist)
 #image("path/does/not/exist")
 
+--- image-info ---
+#let info = image.info("/assets/images/tiger.jpg")
+#test(info, (width: 1024.0, height: 670.0, dpi: none, format: "jpg"))
+#test(image.info("/assets/images/rhino.png").format, "png")
+
+--- image-info-matches-measure ---
+#context {
+  let info = image.info("/assets/images/tiger.jpg")
+  let size = measure(image("/assets/images/tiger.jpg"))
+  test(size.width / size.height, info.width / info.height)
+}
+
+--- image-info-bad-format ---
+// Error: 13-26 unknown image format
+#image.info("./image.typ")
+
 --- image-bad-format ---
 // Error: 2-22 unknown image format
 #image("./image.typ")
@@ -94,3 +94,10 @@
 --- line-infinite-length ---
 // Error: 2-54 cannot create line with infinite length
 #line(start: (0pt, 0pt), end: (float.inf * 1pt, 0pt))
+
+--- rule-size ---
+// A rule is as wide as its length and takes no vertical space.
+#context {
+  test(measure(rule(length: 20pt, stroke: 2pt)), (width: 20pt, height: 0pt))
+  test(measure(rule(length: 20pt, align: center)).width, 20pt)
+}
@@ -111,3 +111,11 @@
 #rect()
 #rect(height: 60pt)
 #rect(width: 60pt)
+
+--- rect-clip-size ---
+// Clipping hides the overflow without affecting the size.
+#context {
+  let body = box(width: 50pt, height: 50pt)
+  test(measure(rect(width: 20pt, height: 20pt, clip: true, body)).width, 20pt)
+  test(measure(circle(width: 20pt, clip: true, body)).height, 20pt)
+}
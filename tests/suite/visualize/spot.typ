@@ -0,0 +1,35 @@
+// Test spot colors.
+
+--- spot-fields ---
+#let ink = spot("PANTONE 300 C", cmyk(100%, 44%, 0%, 0%), tint: 50%)
+#test(type(ink), spot)
+#test(ink.name(), "PANTONE 300 C")
+#test(ink.fallback(), cmyk(100%, 44%, 0%, 0%))
+#test(ink.tint(), 50%)
+#test(ink.overprint(), false)
+#test(spot("Varnish", luma(80%), overprint: true).overprint(), true)
+
+--- spot-repr ---
+#test(repr(spot("Gold", rgb("#d4af37"))), "spot(\"Gold\", rgb(\"#d4af37\"))")
+#test(
+  repr(spot("Gold", luma(50%), tint: 20%, overprint: true)),
+  "spot(\"Gold\", luma(50%), tint: 20%, overprint: true)",
+)
+
+--- spot-equality ---
+#test(spot("Gold", red), spot("Gold", red))
+#test(spot("Gold", red) == spot("Gold", red, tint: 50%), false)
+
+--- spot-stroke ---
+#let ink = spot("Gold", red)
+#test((2pt + ink).paint, ink)
+#test((ink + 2pt).thickness, 2pt)
+#test(stroke(ink).paint, ink)
+
+--- spot-empty-name ---
+// Error: 2-17 spot color name must not be empty
+#spot("", black)
+
+--- spot-tint-out-of-range ---
+// Error: 2-33 tint must be between 0% and 100%
+#spot("Gold", black, tint: 150%)